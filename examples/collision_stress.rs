@@ -0,0 +1,190 @@
+//! Collision broadphase stress test (headless).
+//!
+//! Spawns thousands of moving bullets plus enemies and times the collision
+//! detection pass every frame. Exits with a non-zero status if the p99 frame
+//! time goes over the 60 FPS budget.
+//!
+//! Run: `cargo run --release --example collision_stress [bullets]`
+
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use rand::Rng;
+
+use shoot::game::{
+    Collider, CollisionEvent, CollisionLayer, CollisionMask, CollisionPlugin, GameConfig, GameData,
+    GameState, SweptCollider,
+};
+use shoot::geometry::GeometryBlueprint;
+
+const FRAMES: usize = 300;
+const FRAME_BUDGET: Duration = Duration::from_micros(16_667);
+
+#[derive(Component)]
+struct Velocity(Vec2);
+
+#[derive(Resource, Default)]
+struct HitCount(usize);
+
+fn main() {
+    let bullets: usize = std::env::args()
+        .nth(1)
+        .and_then(|arg| arg.parse().ok())
+        .unwrap_or(4000);
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .insert_resource(GameConfig::default())
+        .insert_resource(GameData::new())
+        .insert_resource(HitCount::default())
+        .insert_state(GameState::Playing)
+        .add_plugins(CollisionPlugin)
+        .add_systems(Update, (move_bodies, count_hits));
+
+    spawn_bodies(app.world_mut(), bullets);
+
+    // Warm-up so first-frame allocations are not measured.
+    app.update();
+
+    let mut samples = Vec::with_capacity(FRAMES);
+    for _ in 0..FRAMES {
+        let start = Instant::now();
+        app.update();
+        samples.push(start.elapsed());
+    }
+    samples.sort();
+
+    let total: Duration = samples.iter().sum();
+    let avg = total / FRAMES as u32;
+    let p99 = samples[FRAMES * 99 / 100];
+    let max = samples[FRAMES - 1];
+    let hits = app.world().resource::<HitCount>().0;
+
+    println!("colliders: {} bullets + enemies", bullets);
+    println!("frames:    {}", FRAMES);
+    println!("avg:       {:.3} ms", avg.as_secs_f64() * 1000.0);
+    println!("p99:       {:.3} ms", p99.as_secs_f64() * 1000.0);
+    println!("max:       {:.3} ms", max.as_secs_f64() * 1000.0);
+    println!(
+        "events:    {} ({:.1}/frame)",
+        hits,
+        hits as f64 / FRAMES as f64
+    );
+
+    if p99 > FRAME_BUDGET {
+        eprintln!(
+            "p99 frame time {:.3} ms exceeds the {:.3} ms budget",
+            p99.as_secs_f64() * 1000.0,
+            FRAME_BUDGET.as_secs_f64() * 1000.0
+        );
+        std::process::exit(1);
+    }
+}
+
+/// Mixed population roughly matching a busy late-game screen.
+fn spawn_bodies(world: &mut World, bullets: usize) {
+    let config = GameConfig::default();
+    let half = Vec2::new(config.window_width, config.window_height) * 0.5;
+    let mut rng = rand::rng();
+    let random_pos = |rng: &mut rand::rngs::ThreadRng| {
+        Vec2::new(
+            rng.random_range(-half.x..half.x),
+            rng.random_range(-half.y..half.y),
+        )
+    };
+
+    let player = GeometryBlueprint::player_raiden_mk1();
+    world.spawn((
+        Transform::from_translation(Vec3::new(0.0, -half.y * 0.6, 0.0)),
+        Collider::new(player.collision.clone(), CollisionLayer::PLAYER)
+            .with_mask(CollisionMask::player_mask()),
+        Velocity(Vec2::ZERO),
+    ));
+
+    let enemy = GeometryBlueprint::default_enemy();
+    for _ in 0..80 {
+        let pos = random_pos(&mut rng);
+        world.spawn((
+            Transform::from_translation(pos.extend(0.0)),
+            Collider::new(enemy.collision.clone(), CollisionLayer::ENEMY)
+                .with_mask(CollisionMask::enemy_mask()),
+            Velocity(Vec2::new(rng.random_range(-40.0..40.0), -60.0)),
+        ));
+    }
+
+    let player_bullet = GeometryBlueprint::default_bullet();
+    let enemy_bullet = GeometryBlueprint::enemy_bullet();
+    for i in 0..bullets {
+        let pos = random_pos(&mut rng);
+        // Roughly a third player bullets, the rest enemy danmaku.
+        let (shape, layer, mask, velocity) = if i % 3 == 0 {
+            (
+                player_bullet.collision.clone(),
+                CollisionLayer::PLAYER_BULLET,
+                CollisionMask::player_bullet_mask(),
+                Vec2::new(0.0, config.bullet_speed),
+            )
+        } else {
+            let angle = rng.random_range(0.0..std::f32::consts::TAU);
+            (
+                enemy_bullet.collision.clone(),
+                CollisionLayer::ENEMY_BULLET,
+                CollisionMask::enemy_bullet_mask(),
+                Vec2::from_angle(angle) * 180.0,
+            )
+        };
+        let mut bullet = world.spawn((
+            Transform::from_translation(pos.extend(0.0)),
+            Collider::new(shape, layer).with_mask(mask),
+            Velocity(velocity),
+        ));
+        // Player bullets use swept tests in the game as well.
+        if layer == CollisionLayer::PLAYER_BULLET {
+            bullet.insert(SweptCollider::default());
+        }
+    }
+}
+
+/// Fixed-step movement. Bodies leaving the screen are despawned and a fresh
+/// copy enters from the opposite edge, so the density stays constant.
+///
+/// Moving the same entity across the screen would make its swept collider
+/// cover the whole jump and inflate the pair count.
+fn move_bodies(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    mut query: Query<(
+        Entity,
+        &mut Transform,
+        &Velocity,
+        &Collider,
+        Has<SweptCollider>,
+    )>,
+) {
+    let half = Vec2::new(config.window_width, config.window_height) * 0.5;
+    let dt = 1.0 / 60.0;
+    for (entity, mut transform, velocity, collider, swept) in query.iter_mut() {
+        let pos = transform.translation.truncate() + velocity.0 * dt;
+        if pos.abs().cmple(half).all() {
+            transform.translation = pos.extend(0.0);
+            continue;
+        }
+
+        let opposite = |v: f32, h: f32| if v.abs() > h { -v.signum() * h } else { v };
+        let entry = Vec2::new(opposite(pos.x, half.x), opposite(pos.y, half.y));
+        commands.entity(entity).despawn();
+        let mut respawned = commands.spawn((
+            Transform::from_translation(entry.extend(0.0)),
+            Collider::new(collider.shape().clone(), collider.layer).with_mask(collider.mask),
+            Velocity(velocity.0),
+        ));
+        if swept {
+            respawned.insert(SweptCollider::default());
+        }
+    }
+}
+
+fn count_hits(mut events: MessageReader<CollisionEvent>, mut hits: ResMut<HitCount>) {
+    hits.0 += events.read().count();
+}
@@ -5,15 +5,20 @@ use rand::Rng;
 use std::f32::consts::PI;
 
 use crate::entities::{
-    spawn_rocket_explosion_particles, Bullet, HitList, Pierce, RocketBullet, WeaponBullet,
-    WeaponType,
+    boss_home, boss_part_layout, boss_total_health, fire_pattern, insert_boss_mechanics,
+    next_chaos_pattern, spawn_boss_hit_flash, spawn_boss_parts, spawn_enemy, spawn_power_up,
+    spawn_telegraph_circle, spawn_telegraph_line, update_boss_movement, BossEscort, BossMovement,
+    BossPart, BossPartKind, BossPartStatus, BossPhaseChanged, BossSpawned, BulletAcceleration,
+    BulletBounce, BulletCurve, BulletPattern, BulletReaim, BulletSource, BulletSplit,
+    EnemyMovement, EnemyType, Health, Player, PowerUpType, TelegraphFollow, TelegraphShot, DOWN,
 };
 use crate::game::{
-    not_upgrading, Collider, CollisionEvent, CollisionLayer, CollisionMask, GameConfig, GameData,
-    GameState,
+    not_upgrading, Collider, CollisionLayer, CollisionMask, GameConfig, GameData, GameState,
+    Scrollable, SweptCollider, WeakPoints, SWEPT_SPEED_THRESHOLD,
 };
 use crate::geometry::{
-    spawn_geometry_entity, CollisionShape, GeometryBlueprint, GeometryShape, ShapeColor, Vec2D,
+    spawn_geometry_entity, CollisionPart, CollisionShape, GeometryBlueprint, GeometryShape,
+    ShapeColor, Vec2D,
 };
 
 /// 创建正多边形顶点
@@ -40,7 +45,7 @@ impl Plugin for BossPlugin {
                 (
                     check_boss_spawn,
                     boss_behavior,
-                    boss_collision_handler,
+                    cancel_bullets_on_phase_change.after(boss_behavior),
                     update_boss_bullets,
                 )
                     .run_if(in_state(GameState::Playing))
@@ -52,39 +57,148 @@ impl Plugin for BossPlugin {
 /// Boss状态资源
 #[derive(Resource, Default)]
 pub struct BossState {
-    /// 当前是否有Boss战
+    /// 当前是否有Boss战（含中型 Boss）
     pub active: bool,
     /// 上次触发Boss的等级
     pub last_boss_level: u32,
-    /// Boss总血量（用于计算百分比）
+    /// 上次触发中型 Boss 的等级
+    pub last_mid_boss_level: u32,
+    /// 本场战斗中的 Boss（多 Boss 战时有多个，每个对应一条血量条），清空即战斗结束
+    pub bosses: Vec<ActiveBoss>,
+    /// 本场战斗已进行的时间（秒）
+    pub elapsed: f32,
+    /// 本场战斗中玩家受击次数（含护盾吸收）
+    pub damage_taken: u32,
+}
+
+impl BossState {
+    /// 开始新的 Boss 战
+    fn begin_fight(&mut self) {
+        self.active = true;
+        self.bosses.clear();
+        self.elapsed = 0.0;
+        self.damage_taken = 0;
+    }
+
+    /// 查找实体所属的 Boss（分裂出的子核心与本体属于同一个 Boss）
+    pub fn find(&self, entity: Entity) -> Option<&ActiveBoss> {
+        self.bosses.iter().find(|b| b.entities.contains(&entity))
+    }
+
+    pub fn find_mut(&mut self, entity: Entity) -> Option<&mut ActiveBoss> {
+        self.bosses
+            .iter_mut()
+            .find(|b| b.entities.contains(&entity))
+    }
+
+    /// 结束并清空 Boss 战
+    pub fn clear(&mut self) {
+        self.active = false;
+        self.bosses.clear();
+    }
+}
+
+/// 战斗中的一个 Boss（HUD 的一条血量条）
+#[derive(Debug, Clone)]
+pub struct ActiveBoss {
+    pub boss_type: BossType,
+    /// 是否为中型 Boss
+    pub mid_boss: bool,
+    /// 总血量（分裂核心包含之后各代子核心）
     pub total_health: i32,
-    /// Boss当前血量
     pub current_health: i32,
-    /// Boss名称
-    pub boss_name: String,
+    /// 当前阶段
+    pub phase: u32,
+    /// 可破坏部件状态（按生成顺序）
+    pub parts: Vec<BossPartStatus>,
+    /// 仍存活的实体（分裂核心会有多个），清空即被击败
+    pub entities: Vec<Entity>,
+    /// 特殊机制状态（召唤、护盾、分裂、招式组），HUD 显示
+    pub mechanic_status: String,
 }
 
-impl BossState {
+impl ActiveBoss {
+    /// HUD 显示名称
+    pub fn name(&self) -> String {
+        if self.mid_boss {
+            format!("{}（中型）", self.boss_type.name())
+        } else {
+            self.boss_type.name().to_string()
+        }
+    }
+
     pub fn health_percent(&self) -> f32 {
         if self.total_health <= 0 {
             return 0.0;
         }
         (self.current_health as f32 / self.total_health as f32 * 100.0).max(0.0)
     }
+
+    /// 指定类型部件的（存活数, 总数）
+    pub fn part_count(&self, kind: BossPartKind) -> (usize, usize) {
+        let of_kind = self.parts.iter().filter(|p| p.kind == kind);
+        let alive = of_kind.clone().filter(|p| p.alive).count();
+        (alive, of_kind.count())
+    }
+
+    /// 核心是否已暴露（装甲板全部被毁；没有装甲板的 Boss 始终暴露）
+    pub fn core_exposed(&self) -> bool {
+        self.part_count(BossPartKind::ArmorPlate).0 == 0
+    }
 }
 
 /// Boss组件
 #[derive(Component)]
 pub struct Boss {
     pub boss_type: BossType,
-    pub health: i32,
-    pub max_health: i32,
     pub phase: u32, // 当前阶段（血量低时切换攻击模式）
     pub attack_timer: f32,
     pub attack_pattern: u32, // 当前攻击模式
     pub move_timer: f32,
     pub score_value: u32,
     pub entered: bool, // 是否已进入战场
+    pub movement: BossMovement,
+    pub pattern_turns: u32, // 当前招式组已使用次数（混沌之眼）
+    pub attack_count: u32,  // 已发动的攻击次数（阶段追加弹幕）
+    pub transition: f32,    // 阶段转换剩余时间（期间无敌、不攻击）
+    pub scale: f32,         // 体型缩放（中型 Boss、分裂子核心更小）
+}
+
+impl Boss {
+    pub fn new(boss_type: BossType) -> Self {
+        Self {
+            boss_type,
+            phase: 1,
+            attack_timer: 2.0, // 进入后延迟攻击
+            attack_pattern: 0,
+            move_timer: 0.0,
+            score_value: boss_type.score_value(),
+            entered: false,
+            movement: BossMovement::new(),
+            pattern_turns: 0,
+            attack_count: 0,
+            transition: 0.0,
+            scale: 1.0,
+        }
+    }
+
+    /// 是否处于阶段转换中
+    pub fn is_transitioning(&self) -> bool {
+        self.transition > 0.0
+    }
+}
+
+/// 阶段阈值：血量低于 60% 进入第 2 阶段，低于 30% 进入第 3 阶段
+pub const BOSS_PHASE_THRESHOLDS: [f32; 2] = [0.6, 0.3];
+/// 阶段转换持续时间（秒）
+const PHASE_TRANSITION_TIME: f32 = 1.5;
+
+/// 按剩余血量比例计算阶段
+pub fn boss_phase_for(health_fraction: f32) -> u32 {
+    1 + BOSS_PHASE_THRESHOLDS
+        .iter()
+        .filter(|threshold| health_fraction <= **threshold)
+        .count() as u32
 }
 
 /// Boss类型（10种不同的Boss）
@@ -197,6 +311,15 @@ pub struct BossBullet {
     pub lifetime: f32,
 }
 
+/// Boss 弹幕的默认子弹（伤害 1）
+const BOSS_BULLET: BulletSource = BulletSource::Boss { damage: 1 };
+/// 预警时间：十字激光、三角战机三连射、圆形守卫环形弹
+const CROSS_LASER_LEAD: f32 = 0.7;
+const TRIANGLE_SHOT_LEAD: f32 = 0.45;
+const GUARDIAN_RING_LEAD: f32 = 0.5;
+/// 圆形守卫环形弹的警示圈半径
+const GUARDIAN_RING_RADIUS: f32 = 90.0;
+
 /// 检查是否应该生成Boss
 fn check_boss_spawn(
     mut commands: Commands,
@@ -204,65 +327,220 @@ fn check_boss_spawn(
     mut boss_state: ResMut<BossState>,
     config: Res<GameConfig>,
     existing_boss: Query<Entity, With<Boss>>,
+    mut boss_spawned: MessageWriter<BossSpawned>,
 ) {
     // 已有Boss战进行中
     if boss_state.active || !existing_boss.is_empty() {
         return;
     }
 
-    // 每10级触发一次Boss
-    let boss_level = (game_data.player_level / 10) * 10;
-    if boss_level == 0 || boss_level <= boss_state.last_boss_level {
+    let level = game_data.player_level;
+    let mut rng = rand::rng();
+    let boss_types = BossType::all();
+    let boss_type = boss_types[rng.random_range(0..boss_types.len())];
+
+    // 每10级触发一次Boss；两次Boss之间（5、15、25…级）触发中型 Boss
+    let boss_level = (level / 10) * 10;
+    let mid_boss_level = if level >= 5 {
+        (level - 5) / 10 * 10 + 5
+    } else {
+        0
+    };
+
+    if boss_level > 0 && boss_level > boss_state.last_boss_level {
+        boss_state.last_boss_level = boss_level;
+        boss_state.begin_fight();
+
+        // 根据等级计算血量（每10级增加100%）
+        let level_multiplier = 1.0 + (boss_level as f32 / 10.0 - 1.0) * 1.0;
+        let health = (boss_type.base_health() as f32 * level_multiplier) as i32;
+
+        if boss_level >= TWIN_BOSS_LEVEL && rng.random_bool(0.4) {
+            // 双 Boss：两种不同的 Boss 分居左右，各自血量降低
+            let others: Vec<BossType> = boss_types
+                .iter()
+                .copied()
+                .filter(|t| *t != boss_type)
+                .collect();
+            let partner = others[rng.random_range(0..others.len())];
+            let partner_health = (partner.base_health() as f32 * level_multiplier) as i32;
+            for (boss_type, health, x) in [
+                (boss_type, health, -TWIN_BOSS_OFFSET),
+                (partner, partner_health, TWIN_BOSS_OFFSET),
+            ] {
+                let health = (health as f32 * TWIN_BOSS_HEALTH_RATIO) as i32;
+                let boss =
+                    spawn_encounter_boss(&mut commands, &config, boss_type, health, x, false);
+                boss_spawned.write(BossSpawned {
+                    entity: boss.entities[0],
+                    boss_type,
+                    mid_boss: false,
+                });
+                boss_state.bosses.push(boss);
+            }
+        } else {
+            let boss = spawn_encounter_boss(&mut commands, &config, boss_type, health, 0.0, false);
+            let entity = boss.entities[0];
+            boss_spawned.write(BossSpawned {
+                entity,
+                boss_type,
+                mid_boss: false,
+            });
+            boss_state.bosses.push(boss);
+            // 精英护卫
+            if boss_level >= ESCORT_BOSS_LEVEL && rng.random_bool(0.5) {
+                spawn_boss_escorts(&mut commands, &config, entity, boss_level);
+            }
+        }
+        log::info!("Boss fight started at level {}", boss_level);
         return;
     }
 
-    // 生成Boss
-    boss_state.last_boss_level = boss_level;
-    boss_state.active = true;
+    if mid_boss_level > 0
+        && mid_boss_level > boss_state.last_mid_boss_level
+        && mid_boss_level > boss_state.last_boss_level
+    {
+        boss_state.last_mid_boss_level = mid_boss_level;
+        boss_state.begin_fight();
 
-    // 随机选择Boss类型
-    let mut rng = rand::rng();
-    let boss_types = BossType::all();
-    let boss_type = boss_types[rng.random_range(0..boss_types.len())];
+        // 中型 Boss：体型缩小、血量约为同期 Boss 的 35%
+        let level_multiplier = 1.0 + (mid_boss_level as f32 / 10.0 - 0.5).max(0.0);
+        let health =
+            (boss_type.base_health() as f32 * level_multiplier * MID_BOSS_HEALTH_RATIO) as i32;
+        let boss = spawn_encounter_boss(&mut commands, &config, boss_type, health, 0.0, true);
+        boss_spawned.write(BossSpawned {
+            entity: boss.entities[0],
+            boss_type,
+            mid_boss: true,
+        });
+        boss_state.bosses.push(boss);
+        log::info!("Mid-boss spawned: {} with {} HP", boss_type.name(), health);
+    }
+}
+
+/// 双 Boss 出现的最低 Boss 等级
+const TWIN_BOSS_LEVEL: u32 = 30;
+/// 精英护卫出现的最低 Boss 等级
+const ESCORT_BOSS_LEVEL: u32 = 20;
+/// 精英护卫的左右站位
+const ESCORT_OFFSET: f32 = 150.0;
+/// 精英护卫相对 Boss 的下移距离
+const ESCORT_DROP: f32 = 50.0;
+/// 双 Boss 的左右站位
+const TWIN_BOSS_OFFSET: f32 = 110.0;
+/// 双 Boss 各自的血量比例
+const TWIN_BOSS_HEALTH_RATIO: f32 = 0.6;
+/// 中型 Boss 的血量比例、体型与分数比例
+const MID_BOSS_HEALTH_RATIO: f32 = 0.35;
+pub const MID_BOSS_SCALE: f32 = 0.65;
+const MID_BOSS_SCORE_RATIO: f32 = 0.4;
 
-    // 根据等级计算血量（每10级增加100%）
-    let level_multiplier = 1.0 + (boss_level as f32 / 10.0 - 1.0) * 1.0;
-    let health = (boss_type.base_health() as f32 * level_multiplier) as i32;
+/// 生成本场战斗中的一个 Boss（从屏幕上方 `x` 处入场）
+fn spawn_encounter_boss(
+    commands: &mut Commands,
+    config: &GameConfig,
+    boss_type: BossType,
+    health: i32,
+    x: f32,
+    mid_boss: bool,
+) -> ActiveBoss {
+    let position = Vec3::new(x, config.window_height / 2.0 + 100.0, 8.0);
+    let mut boss = Boss::new(boss_type);
+    boss.movement.anchor_x = x;
+    let scale = if mid_boss {
+        boss.score_value = (boss.score_value as f32 * MID_BOSS_SCORE_RATIO) as u32;
+        MID_BOSS_SCALE
+    } else {
+        1.0
+    };
+    let entity = spawn_boss_at(commands, boss, position, health, scale);
 
-    boss_state.total_health = health;
-    boss_state.current_health = health;
-    boss_state.boss_name = boss_type.name().to_string();
+    ActiveBoss {
+        boss_type,
+        mid_boss,
+        // 分裂核心把之后各代子核心的血量也计入总血量
+        total_health: boss_total_health(boss_type, health),
+        current_health: boss_total_health(boss_type, health),
+        phase: 1,
+        parts: boss_part_layout(boss_type)
+            .into_iter()
+            .map(|(kind, _)| BossPartStatus { kind, alive: true })
+            .collect(),
+        entities: vec![entity],
+        mechanic_status: String::new(),
+    }
+}
 
-    spawn_boss(&mut commands, &config, boss_type, health);
-    log::info!("Boss spawned: {} with {} HP", boss_type.name(), health);
+/// 在 Boss 两侧生成一对精英护卫（40 级起换成火力更强的炮艇）
+fn spawn_boss_escorts(commands: &mut Commands, config: &GameConfig, owner: Entity, level: u32) {
+    let enemy_type = if level >= 40 {
+        EnemyType::EliteGunship
+    } else {
+        EnemyType::EliteGuard
+    };
+    let y = config.window_height / 2.0 + 60.0;
+    for x in [-ESCORT_OFFSET, ESCORT_OFFSET] {
+        let escort = spawn_enemy(commands, config, Vec3::new(x, y, 5.0), enemy_type);
+        // 护卫由编队系统驱动，不再使用普通敌人的下行移动
+        commands
+            .entity(escort)
+            .remove::<(EnemyMovement, Scrollable)>()
+            .insert(BossEscort {
+                owner,
+                offset: Vec2::new(x, -ESCORT_DROP),
+            });
+    }
 }
 
-/// 生成Boss
-fn spawn_boss(commands: &mut Commands, config: &GameConfig, boss_type: BossType, health: i32) {
-    let blueprint = create_boss_blueprint(boss_type);
-    let position = Vec3::new(0.0, config.window_height / 2.0 + 100.0, 8.0);
+/// 在指定位置生成 Boss（分裂出的子核心也由此生成，`scale` 为体型缩放）
+pub fn spawn_boss_at(
+    commands: &mut Commands,
+    boss: Boss,
+    position: Vec3,
+    health: i32,
+    scale: f32,
+) -> Entity {
+    let boss_type = boss.boss_type;
+    let mut blueprint = create_boss_blueprint(boss_type);
+    blueprint.scale *= scale;
+    let boss = Boss { scale, ..boss };
 
     let entity = spawn_geometry_entity(commands, &blueprint, position);
 
     commands.entity(entity).insert((
-        Boss {
-            boss_type,
-            health,
-            max_health: health,
-            phase: 1,
-            attack_timer: 2.0, // 进入后延迟攻击
-            attack_pattern: 0,
-            move_timer: 0.0,
-            score_value: boss_type.score_value(),
-            entered: false,
-        },
-        Collider::new(blueprint.collision.clone(), CollisionLayer::Enemy)
+        boss,
+        Health::new(health),
+        Collider::new(blueprint.collision.clone(), CollisionLayer::ENEMY)
             .with_mask(CollisionMask::enemy_mask()),
     ));
+    if let Some(weak_points) = boss_weak_points(boss_type) {
+        commands.entity(entity).insert(weak_points);
+    }
+    spawn_boss_parts(commands, entity, boss_type, position, health, scale);
+    insert_boss_mechanics(commands, entity, boss_type);
+    entity
+}
+
+/// 弱点伤害倍率
+const WEAK_POINT_MULTIPLIER: f32 = 2.0;
+
+/// Boss 弱点：与 create_boss_blueprint 中复合碰撞箱的子形状顺序对应；
+/// 弱点露在机体下缘之外，普通子弹无需穿透也能命中
+fn boss_weak_points(boss_type: BossType) -> Option<WeakPoints> {
+    match boss_type {
+        BossType::DiamondKing | BossType::TriangleFighter => {
+            Some(WeakPoints::new(vec![WEAK_POINT_MULTIPLIER]))
+        }
+        BossType::HexFortress => Some(WeakPoints::new(vec![
+            WEAK_POINT_MULTIPLIER,
+            WEAK_POINT_MULTIPLIER,
+        ])),
+        _ => None,
+    }
 }
 
 /// 创建Boss蓝图
-fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
+pub fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
     let color = boss_type.color();
     let size = 60.0;
 
@@ -305,14 +583,7 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     fill: true,
                     stroke_width: 2.0,
                 },
-                // “核心”与外环
-                GeometryShape::Circle {
-                    center: Vec2D::ZERO,
-                    radius: size * 0.18,
-                    color: ShapeColor::new(1.0, 1.0, 0.2, 0.95),
-                    fill: true,
-                    stroke_width: 2.0,
-                },
+                // 中心外环（装饰）
                 GeometryShape::Circle {
                     center: Vec2D::ZERO,
                     radius: size * 0.34,
@@ -320,7 +591,7 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     fill: false,
                     stroke_width: 4.0,
                 },
-                // 四个“炮塔”节点（不影响碰撞，仅装饰）
+                // 三个“炮塔”节点（不影响碰撞，仅装饰）
                 GeometryShape::Circle {
                     center: Vec2D::new(0.0, size * 0.72),
                     radius: size * 0.10,
@@ -336,21 +607,43 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     stroke_width: 3.0,
                 },
                 GeometryShape::Circle {
-                    center: Vec2D::new(0.0, -size * 0.72),
+                    center: Vec2D::new(size * 0.72, 0.0),
                     radius: size * 0.10,
                     color: ShapeColor::new(0.2, 0.9, 1.0, 0.55),
                     fill: false,
                     stroke_width: 3.0,
                 },
+                // “核心”：突出于下方尖角
                 GeometryShape::Circle {
-                    center: Vec2D::new(size * 0.72, 0.0),
-                    radius: size * 0.10,
-                    color: ShapeColor::new(0.2, 0.9, 1.0, 0.55),
-                    fill: false,
-                    stroke_width: 3.0,
+                    center: Vec2D::new(0.0, -size * 0.92),
+                    radius: size * 0.18,
+                    color: ShapeColor::new(1.0, 1.0, 0.2, 0.95),
+                    fill: true,
+                    stroke_width: 2.0,
                 },
             ],
-            collision: CollisionShape::Circle { radius: size * 0.8 },
+            // 子形状 0：下方尖角的核心弱点；1：菱形机体
+            collision: CollisionShape::Compound {
+                parts: vec![
+                    CollisionPart::new(
+                        Vec2D::new(0.0, -size * 0.92),
+                        CollisionShape::Circle {
+                            radius: size * 0.18,
+                        },
+                    ),
+                    CollisionPart::new(
+                        Vec2D::ZERO,
+                        CollisionShape::Polygon {
+                            vertices: vec![
+                                Vec2D::new(0.0, size),
+                                Vec2D::new(-size, 0.0),
+                                Vec2D::new(0.0, -size),
+                                Vec2D::new(size, 0.0),
+                            ],
+                        },
+                    ),
+                ],
+            },
             scale: 1.0,
         },
         BossType::HexFortress => GeometryBlueprint {
@@ -375,7 +668,7 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     fill: true,
                     stroke_width: 2.0,
                 },
-                // 中心核心
+                // 中心装饰
                 GeometryShape::Circle {
                     center: Vec2D::ZERO,
                     radius: size * 0.20,
@@ -390,7 +683,7 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     fill: true,
                     stroke_width: 2.0,
                 },
-                // 顶点炮塔环（6个）
+                // 顶点炮塔环（4个，下方两侧顶点为核心）
                 GeometryShape::Circle {
                     center: Vec2D::new(0.0, -size * 0.86),
                     radius: size * 0.10,
@@ -398,13 +691,6 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     fill: false,
                     stroke_width: 3.0,
                 },
-                GeometryShape::Circle {
-                    center: Vec2D::new(size * 0.75, -size * 0.43),
-                    radius: size * 0.10,
-                    color: ShapeColor::new(1.0, 0.3, 0.3, 0.45),
-                    fill: false,
-                    stroke_width: 3.0,
-                },
                 GeometryShape::Circle {
                     center: Vec2D::new(size * 0.75, size * 0.43),
                     radius: size * 0.10,
@@ -426,15 +712,45 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     fill: false,
                     stroke_width: 3.0,
                 },
+                // 核心：突出于下方两侧顶点
                 GeometryShape::Circle {
-                    center: Vec2D::new(-size * 0.75, -size * 0.43),
-                    radius: size * 0.10,
-                    color: ShapeColor::new(1.0, 0.3, 0.3, 0.45),
-                    fill: false,
-                    stroke_width: 3.0,
+                    center: Vec2D::new(-size * 0.866, -size * 0.5),
+                    radius: size * 0.18,
+                    color: ShapeColor::new(0.15, 0.95, 0.85, 0.9),
+                    fill: true,
+                    stroke_width: 2.0,
+                },
+                GeometryShape::Circle {
+                    center: Vec2D::new(size * 0.866, -size * 0.5),
+                    radius: size * 0.18,
+                    color: ShapeColor::new(0.15, 0.95, 0.85, 0.9),
+                    fill: true,
+                    stroke_width: 2.0,
                 },
             ],
-            collision: CollisionShape::Circle { radius: size * 0.9 },
+            // 子形状 0/1：下方两侧顶点的核心弱点；2：六边形堡垒
+            collision: CollisionShape::Compound {
+                parts: vec![
+                    CollisionPart::new(
+                        Vec2D::new(-size * 0.866, -size * 0.5),
+                        CollisionShape::Circle {
+                            radius: size * 0.18,
+                        },
+                    ),
+                    CollisionPart::new(
+                        Vec2D::new(size * 0.866, -size * 0.5),
+                        CollisionShape::Circle {
+                            radius: size * 0.18,
+                        },
+                    ),
+                    CollisionPart::new(
+                        Vec2D::ZERO,
+                        CollisionShape::Polygon {
+                            vertices: regular_polygon_vertices(6, size),
+                        },
+                    ),
+                ],
+            },
             scale: 1.0,
         },
         BossType::TriangleFighter => GeometryBlueprint {
@@ -505,6 +821,14 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     fill: true,
                     stroke_width: 2.0,
                 },
+                // nose core（弱点，突出于机鼻）
+                GeometryShape::Circle {
+                    center: Vec2D::new(0.0, -size * 0.9),
+                    radius: size * 0.16,
+                    color: ShapeColor::new(1.0, 0.85, 0.3, 0.95),
+                    fill: true,
+                    stroke_width: 2.0,
+                },
                 // twin exhausts (top/back)
                 GeometryShape::Circle {
                     center: Vec2D::new(-size * 0.22, size * 0.62),
@@ -521,7 +845,47 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     stroke_width: 3.0,
                 },
             ],
-            collision: CollisionShape::Circle { radius: size * 0.7 },
+            // 子形状 0：机鼻核心弱点；1：机身三角；2/3：两侧小翼
+            collision: CollisionShape::Compound {
+                parts: vec![
+                    CollisionPart::new(
+                        Vec2D::new(0.0, -size * 0.9),
+                        CollisionShape::Circle {
+                            radius: size * 0.16,
+                        },
+                    ),
+                    CollisionPart::new(
+                        Vec2D::ZERO,
+                        CollisionShape::Polygon {
+                            vertices: vec![
+                                Vec2D::new(0.0, -size),
+                                Vec2D::new(-size * 0.80, size * 0.60),
+                                Vec2D::new(size * 0.80, size * 0.60),
+                            ],
+                        },
+                    ),
+                    CollisionPart::new(
+                        Vec2D::ZERO,
+                        CollisionShape::Polygon {
+                            vertices: vec![
+                                Vec2D::new(-size * 1.05, size * 0.30),
+                                Vec2D::new(-size * 0.55, size * 0.18),
+                                Vec2D::new(-size * 0.52, size * 0.55),
+                            ],
+                        },
+                    ),
+                    CollisionPart::new(
+                        Vec2D::ZERO,
+                        CollisionShape::Polygon {
+                            vertices: vec![
+                                Vec2D::new(size * 1.05, size * 0.30),
+                                Vec2D::new(size * 0.55, size * 0.18),
+                                Vec2D::new(size * 0.52, size * 0.55),
+                            ],
+                        },
+                    ),
+                ],
+            },
             scale: 1.0,
         },
         BossType::StarMothership => {
@@ -531,6 +895,7 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                 let r = if i % 2 == 0 { size } else { size * 0.5 };
                 vertices.push(Vec2D::new(angle.cos() * r, angle.sin() * r));
             }
+            let star_outline = vertices.clone();
             GeometryBlueprint {
                 name: "boss_star_mothership".to_string(),
                 shapes: vec![
@@ -617,7 +982,10 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                         stroke_width: 4.0,
                     },
                 ],
-                collision: CollisionShape::Circle { radius: size * 0.8 },
+                // 星形轮廓（凹多边形）
+                collision: CollisionShape::Polygon {
+                    vertices: star_outline,
+                },
                 scale: 1.0,
             }
         }
@@ -735,7 +1103,23 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     stroke_width: 2.0,
                 },
             ],
-            collision: CollisionShape::Circle { radius: size * 0.8 },
+            // 十字轮廓（凹多边形）
+            collision: CollisionShape::Polygon {
+                vertices: vec![
+                    Vec2D::new(-size * 0.2, -size),
+                    Vec2D::new(size * 0.2, -size),
+                    Vec2D::new(size * 0.2, -size * 0.2),
+                    Vec2D::new(size, -size * 0.2),
+                    Vec2D::new(size, size * 0.2),
+                    Vec2D::new(size * 0.2, size * 0.2),
+                    Vec2D::new(size * 0.2, size),
+                    Vec2D::new(-size * 0.2, size),
+                    Vec2D::new(-size * 0.2, size * 0.2),
+                    Vec2D::new(-size, size * 0.2),
+                    Vec2D::new(-size, -size * 0.2),
+                    Vec2D::new(-size * 0.2, -size * 0.2),
+                ],
+            },
             scale: 1.0,
         },
         BossType::SpiralShooter => GeometryBlueprint {
@@ -755,7 +1139,9 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                     stroke_width: 2.0,
                 },
             ],
-            collision: CollisionShape::Circle { radius: size * 0.9 },
+            collision: CollisionShape::Polygon {
+                vertices: regular_polygon_vertices(8, size),
+            },
             scale: 1.0,
         },
         BossType::SplitCore => GeometryBlueprint {
@@ -799,7 +1185,15 @@ fn create_boss_blueprint(boss_type: BossType) -> GeometryBlueprint {
                 fill: true,
                 stroke_width: 3.0,
             }],
-            collision: CollisionShape::Circle { radius: size * 0.7 },
+            collision: CollisionShape::Polygon {
+                vertices: vec![
+                    Vec2D::new(0.0, -size),
+                    Vec2D::new(-size * 0.6, 0.0),
+                    Vec2D::new(-size * 0.3, size * 0.8),
+                    Vec2D::new(size * 0.3, size * 0.8),
+                    Vec2D::new(size * 0.6, 0.0),
+                ],
+            },
             scale: 1.0,
         },
         BossType::ChaosEye => GeometryBlueprint {
@@ -846,15 +1240,20 @@ fn boss_behavior(
     mut commands: Commands,
     time: Res<Time>,
     config: Res<GameConfig>,
-    mut boss_query: Query<(&mut Transform, &mut Boss)>,
-    mut boss_state: ResMut<BossState>,
+    mut boss_query: Query<(Entity, &mut Transform, &mut Boss, &Health)>,
+    mut phase_changed: MessageWriter<BossPhaseChanged>,
+    player_query: Query<&Transform, (With<Player>, Without<Boss>)>,
 ) {
     let delta = time.delta_secs();
+    let player_pos = player_query
+        .single()
+        .ok()
+        .map(|tf| tf.translation.truncate());
 
-    for (mut transform, mut boss) in boss_query.iter_mut() {
+    for (entity, mut transform, mut boss, health) in boss_query.iter_mut() {
         // 进入阶段：Boss从屏幕上方移动到战斗位置
         if !boss.entered {
-            let target_y = config.window_height / 2.0 - 120.0;
+            let target_y = boss_home(&config).y;
             if transform.translation.y > target_y {
                 transform.translation.y -= 100.0 * delta;
             } else {
@@ -864,28 +1263,42 @@ fn boss_behavior(
         }
 
         // 更新阶段（根据血量）
-        let health_percent = boss.health as f32 / boss.max_health as f32;
-        boss.phase = if health_percent > 0.6 {
-            1
-        } else if health_percent > 0.3 {
-            2
-        } else {
-            3
-        };
-
-        // 更新boss_state
-        boss_state.current_health = boss.health;
+        // 阶段只升不降（分裂出的子核心继承父核心阶段）
+        let phase = boss_phase_for(health.fraction()).max(boss.phase);
+        if phase != boss.phase {
+            phase_changed.write(BossPhaseChanged {
+                entity,
+                boss_type: boss.boss_type,
+                previous_phase: boss.phase,
+                phase,
+            });
+            boss.phase = phase;
+            // 短暂的无敌转换：停止攻击，转换结束后换用新阶段的弹幕
+            boss.transition = PHASE_TRANSITION_TIME;
+            boss.attack_timer = PHASE_TRANSITION_TIME + 0.5;
+            boss.attack_count = 0;
+            spawn_boss_hit_flash(&mut commands, transform.translation);
+        }
+        if boss.is_transitioning() {
+            boss.transition = (boss.transition - delta).max(0.0);
+        }
 
-        // 移动逻辑
-        boss.move_timer += delta;
-        let move_x = (boss.move_timer * 0.5).sin() * 150.0;
-        transform.translation.x = move_x;
+        // 移动逻辑（按 Boss 类型与阶段）
+        update_boss_movement(
+            &mut commands,
+            &config,
+            &mut boss,
+            &mut transform,
+            player_pos,
+            delta,
+        );
 
         // 攻击逻辑
         boss.attack_timer -= delta;
-        if boss.attack_timer <= 0.0 {
+        if boss.attack_timer <= 0.0 && !boss.is_transitioning() {
             // 根据Boss类型和阶段执行攻击
-            execute_boss_attack(&mut commands, &config, &transform, &mut boss);
+            execute_boss_attack(&mut commands, &config, entity, &transform, &mut boss);
+            execute_phase_pattern(&mut commands, &transform, &mut boss, player_pos);
 
             // 重置攻击计时器（阶段越高攻击越快）
             boss.attack_timer = boss_attack_cooldown(boss.boss_type, boss.phase);
@@ -911,117 +1324,259 @@ fn boss_attack_cooldown(boss_type: BossType, phase: u32) -> f32 {
     }
 }
 
+/// 阶段追加弹幕：第 2 阶段每隔一次攻击追加一圈慢速环形弹，
+/// 第 3 阶段每次攻击追加瞄准玩家的三连扇形弹
+fn execute_phase_pattern(
+    commands: &mut Commands,
+    transform: &Transform,
+    boss: &mut Boss,
+    player_pos: Option<Vec2>,
+) {
+    let pos = transform.translation;
+    boss.attack_count += 1;
+
+    match boss.phase {
+        2 if boss.attack_count.is_multiple_of(2) => {
+            let ring = BulletPattern::Spiral {
+                arms: 10,
+                speed: 90.0,
+                step: 0.3,
+            };
+            fire_pattern(commands, &ring, BOSS_BULLET, pos, None, boss.attack_count);
+        }
+        3 => {
+            let aimed = BulletPattern::Aimed {
+                count: 3,
+                spread: 0.4,
+                speed: 260.0,
+            };
+            fire_pattern(commands, &aimed, BOSS_BULLET, pos, player_pos, 0);
+        }
+        _ => {}
+    }
+}
+
+/// 阶段切换时清屏：场上所有 Boss 子弹转换为积分宝石
+fn cancel_bullets_on_phase_change(
+    mut commands: Commands,
+    mut phase_changed: MessageReader<BossPhaseChanged>,
+    bullets: Query<(Entity, &Transform), With<BossBullet>>,
+) {
+    if phase_changed.read().count() == 0 {
+        return;
+    }
+    for (entity, transform) in bullets.iter() {
+        commands.entity(entity).despawn();
+        spawn_power_up(&mut commands, transform.translation, PowerUpType::ScoreGem);
+    }
+}
+
 /// 执行Boss攻击
 fn execute_boss_attack(
     commands: &mut Commands,
     config: &GameConfig,
+    entity: Entity,
     transform: &Transform,
     boss: &mut Boss,
 ) {
     let pos = transform.translation;
-    let mut rng = rand::rng();
 
     match boss.boss_type {
         BossType::DiamondKing => {
             // 扇形弹幕
-            let bullet_count = 5 + boss.phase * 2;
-            let spread = PI / 3.0;
-            for i in 0..bullet_count {
-                let angle =
-                    -PI / 2.0 + spread * ((i as f32 / (bullet_count - 1) as f32) - 0.5) * 2.0;
-                let velocity = Vec2::new(angle.cos() * 200.0, angle.sin() * 200.0);
-                spawn_boss_bullet(commands, pos, velocity, 1);
-            }
+            let fan = BulletPattern::Fan {
+                angle: DOWN,
+                count: 5 + boss.phase * 2,
+                spread: PI * 2.0 / 3.0,
+                speed: 200.0,
+            };
+            fire_pattern(commands, &fan, BOSS_BULLET, pos, None, 0);
         }
         BossType::HexFortress => {
-            // 六向旋转弹幕
-            let base_angle = boss.attack_pattern as f32 * PI / 6.0;
-            for i in 0..6 {
-                let angle = base_angle + (i as f32 / 6.0) * PI * 2.0;
-                let velocity = Vec2::new(angle.cos() * 180.0, angle.sin() * 180.0);
-                spawn_boss_bullet(commands, pos, velocity, 1);
+            // 六向旋转弹幕（慢速出膛后加速）
+            let spiral = BulletPattern::Spiral {
+                arms: 6,
+                speed: 90.0,
+                step: PI / 6.0,
+            };
+            for bullet in fire_pattern(
+                commands,
+                &spiral,
+                BOSS_BULLET,
+                pos,
+                None,
+                boss.attack_pattern,
+            ) {
+                commands.entity(bullet).insert(BulletAcceleration {
+                    acceleration: 160.0,
+                    target_speed: 240.0,
+                });
             }
             boss.attack_pattern = (boss.attack_pattern + 1) % 12;
         }
         BossType::TriangleFighter => {
-            // 三连射
+            // 三连射（预警后出手）
+            let shot = TelegraphShot {
+                pattern: BulletPattern::Fan {
+                    angle: DOWN,
+                    count: 1,
+                    spread: 0.0,
+                    speed: 300.0,
+                },
+                source: BulletSource::Boss { damage: 2 },
+            };
             for i in 0..3 {
-                let offset_x = (i as f32 - 1.0) * 30.0;
-                let bullet_pos = pos + Vec3::new(offset_x, -40.0, 0.0);
-                spawn_boss_bullet(commands, bullet_pos, Vec2::new(0.0, -300.0), 2);
+                let offset = Vec2::new((i as f32 - 1.0) * 30.0, -40.0);
+                let telegraph = spawn_telegraph_line(
+                    commands,
+                    pos + offset.extend(0.0),
+                    DOWN,
+                    config.window_height,
+                    TRIANGLE_SHOT_LEAD,
+                );
+                commands.entity(telegraph).insert((
+                    shot,
+                    TelegraphFollow {
+                        owner: entity,
+                        offset,
+                    },
+                ));
             }
         }
         BossType::StarMothership => {
             // 五角星弹幕
-            for i in 0..5 {
-                let angle = (i as f32 / 5.0) * PI * 2.0 - PI / 2.0;
-                let velocity = Vec2::new(angle.cos() * 150.0, angle.sin() * 150.0);
-                spawn_boss_bullet(commands, pos, velocity, 1);
-            }
+            let ring = BulletPattern::Ring {
+                count: 5,
+                speed: 150.0,
+                offset: DOWN,
+            };
+            fire_pattern(commands, &ring, BOSS_BULLET, pos, None, 0);
         }
         BossType::CircleGuardian => {
-            // 环形弹幕
-            let bullet_count = 8 + boss.phase * 4;
-            for i in 0..bullet_count {
-                let angle = (i as f32 / bullet_count as f32) * PI * 2.0;
-                let velocity = Vec2::new(angle.cos() * 120.0, angle.sin() * 120.0);
-                spawn_boss_bullet(commands, pos, velocity, 1);
-            }
+            // 环形弹幕（警示圈后出手）
+            let shot = TelegraphShot {
+                pattern: BulletPattern::Ring {
+                    count: 8 + boss.phase * 4,
+                    speed: 120.0,
+                    offset: 0.0,
+                },
+                source: BOSS_BULLET,
+            };
+            let telegraph = spawn_telegraph_circle(
+                commands,
+                pos,
+                GUARDIAN_RING_RADIUS * boss.scale,
+                GUARDIAN_RING_LEAD,
+            );
+            commands.entity(telegraph).insert((
+                shot,
+                TelegraphFollow {
+                    owner: entity,
+                    offset: Vec2::ZERO,
+                },
+            ));
         }
         BossType::CrossLaser => {
-            // 十字激光
-            let directions = [
-                Vec2::new(0.0, -1.0),
-                Vec2::new(0.0, 1.0),
-                Vec2::new(-1.0, 0.0),
-                Vec2::new(1.0, 0.0),
-            ];
-            for dir in directions {
-                for j in 0..3 {
-                    let offset = dir * (j as f32 * 20.0);
-                    let bullet_pos = pos + Vec3::new(offset.x, offset.y, 0.0);
-                    spawn_boss_bullet(commands, bullet_pos, dir * 250.0, 1);
-                }
+            // 十字激光（预警后出手）
+            for angle in [DOWN, PI / 2.0, PI, 0.0] {
+                let shot = TelegraphShot {
+                    pattern: BulletPattern::Stream {
+                        angle: Some(angle),
+                        count: 3,
+                        spacing: 20.0,
+                        speed: 250.0,
+                    },
+                    source: BOSS_BULLET,
+                };
+                let telegraph = spawn_telegraph_line(
+                    commands,
+                    pos,
+                    angle,
+                    config.window_height,
+                    CROSS_LASER_LEAD,
+                );
+                commands.entity(telegraph).insert((
+                    shot,
+                    TelegraphFollow {
+                        owner: entity,
+                        offset: Vec2::ZERO,
+                    },
+                ));
             }
         }
         BossType::SpiralShooter => {
-            // 螺旋弹幕
-            let base_angle = boss.attack_pattern as f32 * PI / 8.0;
-            for i in 0..4 {
-                let angle = base_angle + (i as f32 / 4.0) * PI * 2.0;
-                let velocity = Vec2::new(angle.cos() * 160.0, angle.sin() * 160.0);
-                spawn_boss_bullet(commands, pos, velocity, 1);
+            // 螺旋弹幕（第 2 阶段起弹道弯曲）
+            let spiral = BulletPattern::Spiral {
+                arms: 4,
+                speed: 160.0,
+                step: PI / 8.0,
+            };
+            let bullets = fire_pattern(
+                commands,
+                &spiral,
+                BOSS_BULLET,
+                pos,
+                None,
+                boss.attack_pattern,
+            );
+            if boss.phase >= 2 {
+                for bullet in bullets {
+                    commands.entity(bullet).insert(BulletCurve {
+                        angular_velocity: 0.6,
+                        duration: 2.0,
+                    });
+                }
             }
             boss.attack_pattern = (boss.attack_pattern + 1) % 16;
         }
         BossType::SplitCore => {
-            // 四向分裂弹
-            let angles = [0.0, PI / 2.0, PI, PI * 3.0 / 2.0];
-            for angle in angles {
-                let velocity = Vec2::new(angle.cos() * 140.0, angle.sin() * 140.0);
-                spawn_boss_bullet(commands, pos, velocity, 1);
+            // 四向分裂弹：飞行一段后分裂为三向扇形
+            let ring = BulletPattern::Ring {
+                count: 4,
+                speed: 140.0,
+                offset: 0.0,
+            };
+            for bullet in fire_pattern(commands, &ring, BOSS_BULLET, pos, None, 0) {
+                commands.entity(bullet).insert(BulletSplit {
+                    timer: 0.9,
+                    count: 3,
+                    spread: PI / 3.0,
+                    speed: 150.0,
+                });
             }
         }
         BossType::TrackerPrime => {
-            // 向下散射
-            for _ in 0..(3 + boss.phase) {
-                let angle = -PI / 2.0 + rng.random_range(-PI / 4.0..PI / 4.0);
-                let speed = rng.random_range(150.0..250.0);
-                let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
-                spawn_boss_bullet(commands, pos, velocity, 1);
+            // 向下散射（第 2 阶段起短暂飞行后重新瞄准玩家）
+            let spray = BulletPattern::Spray {
+                angle: DOWN,
+                spread: PI / 2.0,
+                count: 3 + boss.phase,
+                min_speed: 150.0,
+                max_speed: 250.0,
+            };
+            let bullets = fire_pattern(commands, &spray, BOSS_BULLET, pos, None, 0);
+            if boss.phase >= 2 {
+                for bullet in bullets {
+                    commands.entity(bullet).insert(BulletReaim {
+                        delay: 0.8,
+                        speed: 220.0,
+                    });
+                }
             }
         }
         BossType::ChaosEye => {
             // 随机模式
-            let pattern = rng.random_range(0..4);
+            // 按招式组出招
+            let pattern = next_chaos_pattern(boss);
             match pattern {
                 0 => {
                     // 环形
-                    for i in 0..12 {
-                        let angle = (i as f32 / 12.0) * PI * 2.0;
-                        let velocity = Vec2::new(angle.cos() * 130.0, angle.sin() * 130.0);
-                        spawn_boss_bullet(commands, pos, velocity, 1);
-                    }
+                    let ring = BulletPattern::Ring {
+                        count: 12,
+                        speed: 130.0,
+                        offset: 0.0,
+                    };
+                    fire_pattern(commands, &ring, BOSS_BULLET, pos, None, 0);
                 }
                 1 => {
                     // 直线
@@ -1037,19 +1592,26 @@ fn execute_boss_attack(
                 }
                 2 => {
                     // X形
-                    let angles = [PI / 4.0, 3.0 * PI / 4.0, 5.0 * PI / 4.0, 7.0 * PI / 4.0];
-                    for angle in angles {
-                        let velocity = Vec2::new(angle.cos() * 180.0, angle.sin() * 180.0);
-                        spawn_boss_bullet(commands, pos, velocity, 1);
-                    }
+                    let cross = BulletPattern::Ring {
+                        count: 4,
+                        speed: 180.0,
+                        offset: PI / 4.0,
+                    };
+                    fire_pattern(commands, &cross, BOSS_BULLET, pos, None, 0);
                 }
                 _ => {
-                    // 随机散射
-                    for _ in 0..8 {
-                        let angle = rng.random_range(0.0..PI * 2.0);
-                        let speed = rng.random_range(100.0..200.0);
-                        let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
-                        spawn_boss_bullet(commands, pos, velocity, 1);
+                    // 随机散射（碰到屏幕边缘反弹一次）
+                    let spray = BulletPattern::Spray {
+                        angle: 0.0,
+                        spread: PI * 2.0,
+                        count: 8,
+                        min_speed: 100.0,
+                        max_speed: 200.0,
+                    };
+                    for bullet in fire_pattern(commands, &spray, BOSS_BULLET, pos, None, 0) {
+                        commands
+                            .entity(bullet)
+                            .insert(BulletBounce { remaining: 1 });
                     }
                 }
             }
@@ -1058,7 +1620,12 @@ fn execute_boss_attack(
 }
 
 /// 生成Boss子弹
-fn spawn_boss_bullet(commands: &mut Commands, position: Vec3, velocity: Vec2, damage: i32) {
+pub fn spawn_boss_bullet(
+    commands: &mut Commands,
+    position: Vec3,
+    velocity: Vec2,
+    damage: i32,
+) -> Entity {
     let blueprint = GeometryBlueprint {
         name: "boss_bullet".to_string(),
         shapes: vec![GeometryShape::Circle {
@@ -1079,9 +1646,13 @@ fn spawn_boss_bullet(commands: &mut Commands, position: Vec3, velocity: Vec2, da
             velocity,
             lifetime: 5.0,
         },
-        Collider::new(blueprint.collision.clone(), CollisionLayer::EnemyBullet)
+        Collider::new(blueprint.collision.clone(), CollisionLayer::ENEMY_BULLET)
             .with_mask(CollisionMask::enemy_bullet_mask()),
     ));
+    if velocity.length() >= SWEPT_SPEED_THRESHOLD {
+        commands.entity(entity).insert(SweptCollider::default());
+    }
+    entity
 }
 
 /// 更新Boss子弹
@@ -1112,159 +1683,17 @@ fn update_boss_bullets(
     }
 }
 
-/// Boss碰撞处理
-fn boss_collision_handler(
-    mut commands: Commands,
-    mut collision_events: MessageReader<CollisionEvent>,
-    mut game_data: ResMut<GameData>,
-    mut boss_state: ResMut<BossState>,
-    mut boss_query: Query<&mut Boss>,
-    bullets: Query<&Bullet>,
-    weapon_bullets: Query<&WeaponBullet>,
-    mut pierce: Query<&mut Pierce>,
-    mut hit_list: Query<&mut HitList>,
-    rockets: Query<&RocketBullet>,
-    transforms: Query<&Transform>,
-) {
-    for event in collision_events.read() {
-        // 检查是否涉及Boss
-        let boss_entity = if event.layer_a == CollisionLayer::Enemy {
-            if boss_query.get(event.entity_a).is_ok() {
-                Some(event.entity_a)
-            } else {
-                None
-            }
-        } else if event.layer_b == CollisionLayer::Enemy {
-            if boss_query.get(event.entity_b).is_ok() {
-                Some(event.entity_b)
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-
-        let Some(boss_entity) = boss_entity else {
-            continue;
-        };
-
-        let other_layer = if boss_query.get(event.entity_a).is_ok() {
-            event.layer_b
-        } else {
-            event.layer_a
-        };
-
-        let other_entity = if boss_query.get(event.entity_a).is_ok() {
-            event.entity_b
-        } else {
-            event.entity_a
-        };
-
-        if other_layer != CollisionLayer::PlayerBullet {
-            continue;
-        }
-
-        // 计算伤害
-        let damage = if let Ok(bullet) = bullets.get(other_entity) {
-            // 受击闪烁：尽量使用子弹当前位置作为命中点
-            let boss_pos = transforms
-                .get(boss_entity)
-                .map(|t| t.translation)
-                .unwrap_or_default();
-            let impact = transforms
-                .get(other_entity)
-                .map(|t| t.translation)
-                .unwrap_or(boss_pos);
-            crate::entities::spawn_hit_sparks(&mut commands, impact);
-            crate::entities::spawn_boss_hit_flash(&mut commands, impact);
-            commands.entity(other_entity).despawn();
-            bullet.damage
-        } else if let Ok(weapon_bullet) = weapon_bullets.get(other_entity) {
-            // 检查是否已命中
-            if let Ok(mut hits) = hit_list.get_mut(other_entity) {
-                if hits.entities.contains(&boss_entity) {
-                    continue;
-                }
-                hits.entities.push(boss_entity);
-            }
-
-            let boss_pos = transforms
-                .get(boss_entity)
-                .map(|t| t.translation)
-                .unwrap_or_default();
-            let bullet_pos = transforms
-                .get(other_entity)
-                .map(|t| t.translation)
-                .unwrap_or(boss_pos);
-            let impact = match weapon_bullet.weapon_type {
-                WeaponType::Laser | WeaponType::Beam => boss_pos,
-                _ => bullet_pos,
-            };
-            crate::entities::spawn_hit_sparks(&mut commands, impact);
-            crate::entities::spawn_boss_hit_flash(&mut commands, impact);
-
-            // 导弹爆炸
-            if weapon_bullet.weapon_type == WeaponType::Rocket {
-                if let Ok(rocket) = rockets.get(other_entity) {
-                    if let Ok(rocket_tf) = transforms.get(other_entity) {
-                        let shard_count = ((rocket.explosion_radius / 4.0) as u32).clamp(10, 28);
-                        spawn_rocket_explosion_particles(
-                            &mut commands,
-                            rocket_tf.translation,
-                            shard_count,
-                            rocket.speed,
-                        );
-                    }
-                }
-                commands.entity(other_entity).despawn();
-            } else {
-                // 穿透检查
-                let mut should_despawn = true;
-                if let Ok(mut p) = pierce.get_mut(other_entity) {
-                    if p.remaining == u32::MAX {
-                        should_despawn = false;
-                    } else if p.remaining > 1 {
-                        p.remaining -= 1;
-                        should_despawn = false;
-                    }
-                }
-                if should_despawn {
-                    commands.entity(other_entity).despawn();
-                }
-            }
-            weapon_bullet.damage
-        } else {
-            continue;
-        };
-
-        // 应用伤害
-        if let Ok(mut boss) = boss_query.get_mut(boss_entity) {
-            boss.health -= damage;
-            boss_state.current_health = boss.health;
-
-            if boss.health <= 0 {
-                let score = boss.score_value;
-                commands.entity(boss_entity).despawn();
-                game_data.add_score(score);
-                boss_state.active = false;
-                boss_state.current_health = 0;
-                log::info!("Boss defeated! Score: {}", score);
-            }
-        }
-    }
-}
-
 /// 清理Boss
 fn despawn_boss(
     mut commands: Commands,
     mut boss_state: ResMut<BossState>,
     query: Query<Entity, With<Boss>>,
+    part_query: Query<Entity, With<BossPart>>,
     bullet_query: Query<Entity, With<BossBullet>>,
 ) {
-    boss_state.active = false;
-    boss_state.current_health = 0;
+    boss_state.clear();
 
-    for entity in query.iter() {
+    for entity in query.iter().chain(part_query.iter()) {
         commands.entity(entity).despawn();
     }
     for entity in bullet_query.iter() {
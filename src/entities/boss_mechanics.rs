@@ -0,0 +1,380 @@
+//! Boss 特殊机制
+//! 星形母舰召唤小兵、圆形护盾的无敌护盾相位、分裂核心分裂、混沌之眼随机招式组、
+//! 高等级 Boss 的精英护卫
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::game::{not_upgrading, GameConfig, GameState};
+use crate::geometry::{
+    spawn_geometry_entity, CollisionShape, GeometryBlueprint, GeometryShape, ShapeColor, Vec2D,
+};
+
+use super::boss::{spawn_boss_at, Boss, BossState, BossType};
+use super::enemy::{spawn_enemy, EnemyType};
+use super::events::BossPhaseChanged;
+use super::weapons::spawn_hit_sparks;
+use super::Invulnerable;
+
+/// Boss 机制插件
+pub struct BossMechanicsPlugin;
+
+impl Plugin for BossMechanicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                summon_minions,
+                guardian_shield,
+                escort_formation,
+                update_boss_mechanic_status,
+            )
+                .run_if(in_state(GameState::Playing))
+                .run_if(not_upgrading),
+        );
+    }
+}
+
+/// 同一母舰同时存在的小兵上限
+const MAX_MINIONS: usize = 6;
+/// 分裂核心最多分裂的代数（1 → 2 → 4）
+const MAX_SPLIT_GENERATION: u32 = 2;
+/// 每个子核心的血量占父核心的比例
+const SPLIT_HEALTH_RATIO: f32 = 0.4;
+/// 每代子核心的体型缩放
+const SPLIT_SCALE: f32 = 0.7;
+/// 护盾相位间隔与基础持续时间（秒）
+const SHIELD_COOLDOWN: f32 = 9.0;
+const SHIELD_DURATION: f32 = 2.5;
+/// 护卫向编队位置靠拢的速率
+const ESCORT_FOLLOW_RATE: f32 = 3.0;
+/// Boss 消失后护卫撤离的速度
+const ESCORT_RETREAT_SPEED: f32 = 220.0;
+
+/// 混沌之眼的招式组：名称 + 依次使用的攻击模式
+pub const CHAOS_PATTERN_SETS: &[(&str, &[u32])] = &[
+    ("环形风暴", &[0, 0, 2]),
+    ("弹雨", &[1, 3, 1]),
+    ("十字交错", &[2, 1, 2]),
+    ("混沌乱舞", &[0, 1, 2, 3]),
+];
+/// 每组招式重复的轮数
+const CHAOS_SET_ROUNDS: u32 = 2;
+
+/// 召唤者（星形母舰）
+#[derive(Component)]
+pub struct MinionSummoner {
+    pub timer: f32,
+}
+
+/// 被 Boss 召唤的小兵
+#[derive(Component)]
+pub struct BossMinion {
+    pub owner: Entity,
+}
+
+/// 随 Boss 一同登场的精英护卫
+#[derive(Component)]
+pub struct BossEscort {
+    pub owner: Entity,
+    /// 相对 Boss 的编队位置
+    pub offset: Vec2,
+}
+
+/// 护盾相位（圆形护盾）
+#[derive(Component)]
+pub struct GuardianShield {
+    /// 距下次展开护盾的时间
+    pub cooldown: f32,
+    /// 护盾剩余时间（0 表示未展开）
+    pub remaining: f32,
+    /// 护盾特效实体（Boss 的子实体）
+    bubble: Option<Entity>,
+}
+
+/// 分裂核心的代数（0 为本体）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BossSplit {
+    pub generation: u32,
+}
+
+/// 按 Boss 类型挂上机制组件
+pub fn insert_boss_mechanics(commands: &mut Commands, entity: Entity, boss_type: BossType) {
+    match boss_type {
+        BossType::StarMothership => {
+            commands
+                .entity(entity)
+                .insert(MinionSummoner { timer: 4.0 });
+        }
+        BossType::CircleGuardian => {
+            commands.entity(entity).insert(GuardianShield {
+                cooldown: 5.0,
+                remaining: 0.0,
+                bubble: None,
+            });
+        }
+        BossType::SplitCore => {
+            commands.entity(entity).insert(BossSplit { generation: 0 });
+        }
+        _ => {}
+    }
+}
+
+/// 分裂核心的总血量预算：本体 + 之后所有代子核心
+pub fn boss_total_health(boss_type: BossType, health: i32) -> i32 {
+    if boss_type != BossType::SplitCore {
+        return health;
+    }
+    let mut total = 0;
+    let mut count = 1;
+    let mut generation_health = health;
+    for _ in 0..=MAX_SPLIT_GENERATION {
+        total += generation_health * count;
+        count *= 2;
+        generation_health = split_health(generation_health);
+    }
+    total
+}
+
+fn split_health(parent_max: i32) -> i32 {
+    ((parent_max as f32 * SPLIT_HEALTH_RATIO).ceil() as i32).max(1)
+}
+
+/// 分裂核心被击破：未到最大代数时分裂为两个更小的核心，返回新核心
+pub fn spawn_split_cores(
+    commands: &mut Commands,
+    parent: &Boss,
+    split: BossSplit,
+    position: Vec3,
+    parent_max_health: i32,
+) -> Vec<Entity> {
+    if split.generation >= MAX_SPLIT_GENERATION {
+        return Vec::new();
+    }
+    let generation = split.generation + 1;
+    let health = split_health(parent_max_health);
+    let scale = parent.scale * SPLIT_SCALE;
+
+    spawn_hit_sparks(commands, position);
+    [-1.0, 1.0]
+        .into_iter()
+        .map(|side: f32| {
+            let pos = position + Vec3::new(side * 50.0 * scale, 0.0, 0.0);
+            let mut boss = Boss::new(parent.boss_type);
+            boss.phase = parent.phase;
+            boss.score_value = parent.score_value / 2;
+            boss.movement.direction = side;
+            boss.movement.anchor_x = parent.movement.anchor_x;
+            let child = spawn_boss_at(commands, boss, pos, health, scale);
+            commands.entity(child).insert(BossSplit { generation });
+            child
+        })
+        .collect()
+}
+
+/// 星形母舰：定时召唤小兵（有数量上限），阶段越高召唤越多越快
+fn summon_minions(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut summoners: Query<(Entity, &Transform, &Boss, &mut MinionSummoner)>,
+    minions: Query<&BossMinion>,
+) {
+    for (entity, transform, boss, mut summoner) in summoners.iter_mut() {
+        if !boss.entered {
+            continue;
+        }
+        summoner.timer -= time.delta_secs();
+        if summoner.timer > 0.0 {
+            continue;
+        }
+        summoner.timer = (7.0 - boss.phase as f32 * 1.5).max(3.0);
+
+        let alive = minions.iter().filter(|m| m.owner == entity).count();
+        let count = ((1 + boss.phase) as usize).min(MAX_MINIONS.saturating_sub(alive));
+        let mut rng = rand::rng();
+        for i in 0..count {
+            let offset_x = (i as f32 - (count as f32 - 1.0) / 2.0) * 50.0;
+            let position = transform.translation + Vec3::new(offset_x, -60.0, 0.0);
+            let position = position.with_z(5.0);
+            let enemy_type = if boss.phase >= 3 && rng.random_bool(0.4) {
+                EnemyType::Diamond
+            } else {
+                EnemyType::Small
+            };
+            let minion = spawn_enemy(&mut commands, &config, position, enemy_type);
+            commands.entity(minion).insert(BossMinion { owner: entity });
+            spawn_hit_sparks(&mut commands, position);
+        }
+    }
+}
+
+/// 精英护卫：保持在 Boss 两侧的编队位置；Boss 消失后向上撤离并在出屏后移除
+fn escort_formation(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut escorts: Query<(Entity, &mut Transform, &BossEscort), Without<Boss>>,
+    bosses: Query<&Transform, With<Boss>>,
+) {
+    let delta = time.delta_secs();
+    let top = config.window_height / 2.0 + 80.0;
+
+    for (entity, mut transform, escort) in escorts.iter_mut() {
+        match bosses.get(escort.owner) {
+            Ok(owner) => {
+                let slot = owner.translation.truncate() + escort.offset;
+                let pos = transform.translation.truncate();
+                let next = pos.lerp(slot, (delta * ESCORT_FOLLOW_RATE).min(1.0));
+                transform.translation.x = next.x;
+                transform.translation.y = next.y;
+            }
+            Err(_) => {
+                transform.translation.y += ESCORT_RETREAT_SPEED * delta;
+                if transform.translation.y > top {
+                    commands.entity(entity).try_despawn();
+                }
+            }
+        }
+    }
+}
+
+/// 圆形护盾：周期性展开无敌护盾；进入新阶段时立即展开
+fn guardian_shield(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut phase_changed: MessageReader<BossPhaseChanged>,
+    mut guardians: Query<(Entity, &Boss, &mut GuardianShield)>,
+) {
+    let delta = time.delta_secs();
+    let changed: Vec<Entity> = phase_changed.read().map(|e| e.entity).collect();
+
+    for (entity, boss, mut shield) in guardians.iter_mut() {
+        if !boss.entered {
+            continue;
+        }
+
+        if shield.remaining > 0.0 {
+            shield.remaining -= delta;
+            if shield.remaining <= 0.0 {
+                shield.remaining = 0.0;
+                commands.entity(entity).remove::<Invulnerable>();
+                if let Some(bubble) = shield.bubble.take() {
+                    commands.entity(bubble).despawn();
+                }
+            }
+            continue;
+        }
+
+        shield.cooldown -= delta;
+        if shield.cooldown > 0.0 && !changed.contains(&entity) {
+            continue;
+        }
+
+        // 展开护盾
+        shield.cooldown = SHIELD_COOLDOWN;
+        shield.remaining = SHIELD_DURATION + boss.phase as f32 * 0.5;
+        let bubble = spawn_geometry_entity(&mut commands, &shield_bubble_blueprint(), Vec3::Z);
+        commands
+            .entity(entity)
+            .insert(Invulnerable)
+            .add_child(bubble);
+        shield.bubble = Some(bubble);
+    }
+}
+
+/// 护盾特效
+fn shield_bubble_blueprint() -> GeometryBlueprint {
+    GeometryBlueprint {
+        name: "boss_guardian_shield".to_string(),
+        shapes: vec![
+            GeometryShape::Circle {
+                center: Vec2D::ZERO,
+                radius: 92.0,
+                color: ShapeColor::new(0.4, 0.85, 1.0, 0.18),
+                fill: true,
+                stroke_width: 0.0,
+            },
+            GeometryShape::Circle {
+                center: Vec2D::ZERO,
+                radius: 92.0,
+                color: ShapeColor::new(0.6, 0.95, 1.0, 0.85),
+                fill: false,
+                stroke_width: 3.0,
+            },
+        ],
+        collision: CollisionShape::Circle { radius: 92.0 },
+        scale: 1.0,
+    }
+}
+
+/// 混沌之眼：按当前招式组选出本次攻击模式，用完一组后随机换组
+pub fn next_chaos_pattern(boss: &mut Boss) -> u32 {
+    let set = CHAOS_PATTERN_SETS[boss.attack_pattern as usize % CHAOS_PATTERN_SETS.len()].1;
+    let total = set.len() as u32 * CHAOS_SET_ROUNDS;
+    if boss.pattern_turns >= total {
+        let mut rng = rand::rng();
+        let mut next = rng.random_range(0..CHAOS_PATTERN_SETS.len() as u32);
+        if next == boss.attack_pattern {
+            next = (next + 1) % CHAOS_PATTERN_SETS.len() as u32;
+        }
+        boss.attack_pattern = next;
+        boss.pattern_turns = 0;
+        return next_chaos_pattern(boss);
+    }
+    let pattern = set[boss.pattern_turns as usize % set.len()];
+    boss.pattern_turns += 1;
+    pattern
+}
+
+/// 汇总每个 Boss 的阶段与机制状态供 HUD 显示
+fn update_boss_mechanic_status(
+    mut boss_state: ResMut<BossState>,
+    bosses: Query<(&Boss, Option<&MinionSummoner>, Option<&GuardianShield>)>,
+    minions: Query<&BossMinion>,
+) {
+    if !boss_state.active {
+        return;
+    }
+    for active in boss_state.bosses.iter_mut() {
+        // 分裂出的子核心都计入同一个 Boss，阶段取其中最高者
+        let Some(entity) = active.entities.first().copied() else {
+            continue;
+        };
+        active.phase = active
+            .entities
+            .iter()
+            .filter_map(|e| bosses.get(*e).ok())
+            .map(|(boss, _, _)| boss.phase)
+            .max()
+            .unwrap_or(active.phase);
+        let Ok((boss, summoner, shield)) = bosses.get(entity) else {
+            continue;
+        };
+
+        let status = match boss.boss_type {
+            BossType::StarMothership => summoner.map(|s| {
+                format!(
+                    "小兵 {}/{}  下次召唤 {:.0}s",
+                    minions.iter().filter(|m| m.owner == entity).count(),
+                    MAX_MINIONS,
+                    s.timer.max(0.0).ceil()
+                )
+            }),
+            BossType::CircleGuardian => shield.map(|s| {
+                if s.remaining > 0.0 {
+                    format!("护盾展开·无敌 {:.1}s", s.remaining)
+                } else {
+                    format!("护盾冷却 {:.0}s", s.cooldown.max(0.0).ceil())
+                }
+            }),
+            BossType::SplitCore => Some(format!("核心剩余 {}", active.entities.len())),
+            BossType::ChaosEye => {
+                let index = boss.attack_pattern as usize % CHAOS_PATTERN_SETS.len();
+                Some(format!("招式组：{}", CHAOS_PATTERN_SETS[index].0))
+            }
+            _ => None,
+        };
+        active.mechanic_status = status.unwrap_or_default();
+    }
+}
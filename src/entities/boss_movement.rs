@@ -0,0 +1,232 @@
+//! Boss 移动模式
+//! 每种 Boss 按类型与阶段使用不同的移动方式（摆动、冲刺、跟随、瞬移等）
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::game::GameConfig;
+
+use super::boss::{Boss, BossType};
+use super::weapons::spawn_hit_sparks;
+
+/// Boss 战斗位置距屏幕顶部的距离
+const HOME_OFFSET_Y: f32 = 120.0;
+/// Boss 与屏幕左右边缘保持的距离
+const EDGE_MARGIN: f32 = 90.0;
+/// Boss 中心允许到达的离屏幕边缘最近距离
+const SCREEN_MARGIN: f32 = 40.0;
+/// 入场后从当前位置过渡到摆动/盘旋轨迹所需的时间
+const SETTLE_TIME: f32 = 1.2;
+
+/// 冲刺型 Boss 的动作阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DashStage {
+    /// 在战斗位置游弋
+    #[default]
+    Hover,
+    /// 冲刺前蓄力（原地抖动）
+    Windup,
+    /// 冲向锁定位置
+    Dash,
+    /// 返回战斗位置
+    Return,
+}
+
+/// Boss 移动状态（挂在 Boss 组件上，由 boss_behavior 每帧推进）
+#[derive(Debug, Clone, Default)]
+pub struct BossMovement {
+    /// 当前动作剩余时间 / 冷却
+    pub timer: f32,
+    /// 冲刺目标、踱步目标等
+    pub target: Vec2,
+    pub dash_stage: DashStage,
+    /// 水平往返方向（1 / -1）
+    pub direction: f32,
+    /// 战斗位置的横坐标（双 Boss 时分居左右）
+    pub anchor_x: f32,
+    /// 摆动/盘旋的累计相位（按阶段速度逐帧累加，换阶段时轨迹连续）
+    pub phase_angle: f32,
+    /// 入场后向轨迹过渡的进度（0~1）
+    pub settle: f32,
+}
+
+impl BossMovement {
+    pub fn new() -> Self {
+        Self {
+            timer: 2.0,
+            direction: 1.0,
+            ..Default::default()
+        }
+    }
+}
+
+/// 战斗位置（入场完成时所在的位置）
+pub fn boss_home(config: &GameConfig) -> Vec2 {
+    Vec2::new(0.0, config.window_height / 2.0 - HOME_OFFSET_Y)
+}
+
+/// 按 Boss 类型与阶段推进一帧移动
+/// `player_pos` 为玩家位置（玩家不存在时为 None）
+pub fn update_boss_movement(
+    commands: &mut Commands,
+    config: &GameConfig,
+    boss: &mut Boss,
+    transform: &mut Transform,
+    player_pos: Option<Vec2>,
+    delta: f32,
+) {
+    boss.move_timer += delta;
+    let time = boss.move_timer;
+    let phase = boss.phase;
+    let movement = &mut boss.movement;
+    let home = boss_home(config) + Vec2::X * movement.anchor_x;
+    let half_w = config.window_width / 2.0 - EDGE_MARGIN;
+    // 阶段越高动作越快
+    let speed_scale = 1.0 + (phase.max(1) - 1) as f32 * 0.35;
+    let pos = transform.translation.truncate();
+    movement.phase_angle += speed_scale * delta;
+    movement.settle = (movement.settle + delta / SETTLE_TIME).min(1.0);
+    let angle = movement.phase_angle;
+    // 轨迹型移动：从入场位置逐渐并入轨迹，避免首帧跳变
+    let settle = movement.settle * movement.settle;
+    let follow = |track: Vec2| pos.lerp(track, settle);
+
+    let next = match boss.boss_type {
+        // 左右摆动
+        BossType::DiamondKing => follow(Vec2::new(home.x + (angle * 0.5).sin() * 150.0, home.y)),
+        // 停在中央缓慢自转（部件与弹幕跟随朝向）
+        BossType::HexFortress => {
+            transform.rotate_z(0.3 * speed_scale * delta);
+            pos.lerp(home, (delta * 2.0).min(1.0))
+        }
+        BossType::TriangleFighter => {
+            dash_toward_player(config, movement, pos, home, player_pos, speed_scale, delta)
+        }
+        // 横向 8 字
+        BossType::StarMothership => follow(Vec2::new(
+            home.x + (angle * 0.4).sin() * 180.0,
+            home.y + (angle * 0.8).sin() * 30.0,
+        )),
+        // 绕战斗位置小圈盘旋
+        BossType::CircleGuardian => {
+            let angle = angle * 0.8;
+            follow(home + Vec2::new(angle.cos() * 70.0, angle.sin() * 35.0))
+        }
+        // 在三条射击列之间踱步
+        BossType::CrossLaser => {
+            movement.timer -= delta;
+            if movement.timer <= 0.0 {
+                let columns = [home.x - half_w * 0.6, home.x, home.x + half_w * 0.6];
+                let column = columns[rand::rng().random_range(0..columns.len())];
+                movement.target = Vec2::new(column, home.y);
+                movement.timer = 2.5 / speed_scale;
+            }
+            pos.lerp(movement.target, (delta * 3.0).min(1.0))
+        }
+        // 大圈盘旋
+        BossType::SpiralShooter => {
+            let angle = angle * 0.6;
+            follow(home + Vec2::new(angle.cos() * 160.0, angle.sin() * 50.0))
+        }
+        // 匀速往返，碰到边缘反向
+        BossType::SplitCore => {
+            let mut x = pos.x + movement.direction * 110.0 * speed_scale * delta;
+            if x.abs() > half_w {
+                x = x.clamp(-half_w, half_w);
+                movement.direction = -movement.direction;
+            }
+            Vec2::new(x, home.y)
+        }
+        // 缓慢漂移跟随玩家的横坐标
+        BossType::TrackerPrime => {
+            let target_x = player_pos.map_or(0.0, |p| p.x).clamp(-half_w, half_w);
+            let drift = (delta * 0.8 * speed_scale).min(1.0);
+            Vec2::new(
+                pos.x + (target_x - pos.x) * drift,
+                home.y + (time * 1.2).sin() * 20.0,
+            )
+        }
+        // 定时瞬移到随机位置
+        BossType::ChaosEye => {
+            movement.timer -= delta;
+            if movement.timer <= 0.0 {
+                let mut rng = rand::rng();
+                let target = Vec2::new(
+                    rng.random_range(-half_w..half_w),
+                    home.y - rng.random_range(0.0..120.0),
+                );
+                spawn_hit_sparks(commands, transform.translation);
+                spawn_hit_sparks(commands, target.extend(transform.translation.z));
+                movement.timer = 3.0 / speed_scale;
+                target
+            } else {
+                pos
+            }
+        }
+    };
+
+    // 双 Boss 偏离中央时不让摆动带出屏幕
+    let max_x = config.window_width / 2.0 - SCREEN_MARGIN;
+    transform.translation.x = next.x.clamp(-max_x, max_x);
+    transform.translation.y = next.y;
+}
+
+/// 冲刺：游弋 → 蓄力 → 冲向玩家（锁定位置）→ 返回
+fn dash_toward_player(
+    config: &GameConfig,
+    movement: &mut BossMovement,
+    pos: Vec2,
+    home: Vec2,
+    player_pos: Option<Vec2>,
+    speed_scale: f32,
+    delta: f32,
+) -> Vec2 {
+    let bottom = -config.window_height / 2.0 + 80.0;
+    movement.timer -= delta;
+
+    match movement.dash_stage {
+        DashStage::Hover => {
+            let x = pos.x + movement.direction * 90.0 * delta;
+            if (x - home.x).abs() > 150.0 {
+                movement.direction = -movement.direction;
+            }
+            if movement.timer <= 0.0 {
+                if let Some(player) = player_pos {
+                    movement.dash_stage = DashStage::Windup;
+                    movement.timer = 0.6 / speed_scale;
+                    movement.target = Vec2::new(player.x, player.y.max(bottom));
+                }
+            }
+            Vec2::new(x.clamp(home.x - 150.0, home.x + 150.0), home.y)
+        }
+        DashStage::Windup => {
+            if movement.timer <= 0.0 {
+                movement.dash_stage = DashStage::Dash;
+            }
+            // 蓄力抖动
+            let shake = rand::rng().random_range(-3.0..3.0);
+            Vec2::new(pos.x + shake, pos.y)
+        }
+        DashStage::Dash => {
+            let to_target = movement.target - pos;
+            let step = 520.0 * speed_scale * delta;
+            if to_target.length() <= step {
+                movement.dash_stage = DashStage::Return;
+                movement.target
+            } else {
+                pos + to_target.normalize() * step
+            }
+        }
+        DashStage::Return => {
+            let to_home = Vec2::new(pos.x.clamp(home.x - 150.0, home.x + 150.0), home.y) - pos;
+            let step = 220.0 * delta;
+            if to_home.length() <= step {
+                movement.dash_stage = DashStage::Hover;
+                movement.timer = 3.0 / speed_scale;
+                pos + to_home
+            } else {
+                pos + to_home.normalize() * step
+            }
+        }
+    }
+}
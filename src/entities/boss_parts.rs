@@ -0,0 +1,295 @@
+//! Boss 部件系统
+//! 炮塔、装甲板等可破坏部件：各自拥有生命值与碰撞箱，跟随 Boss 移动；
+//! 炮塔被毁后停止开火，装甲板全部被毁后核心（Boss 本体）失去护甲
+
+use bevy::prelude::*;
+
+use crate::game::{not_upgrading, Collider, CollisionLayer, CollisionMask, GameData, GameState};
+use crate::geometry::{
+    spawn_geometry_entity, CollisionShape, GeometryBlueprint, GeometryShape, ShapeColor, Vec2D,
+};
+
+use super::boss::{Boss, BossState, BossType};
+use super::bullet_pattern::{BulletEmitter, BulletPattern, BulletSource};
+use super::events::BossPartDestroyed;
+use super::weapons::spawn_rocket_explosion_particles;
+use super::{Armor, Health};
+
+/// Boss 部件插件
+pub struct BossPartPlugin;
+
+impl Plugin for BossPartPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                follow_boss_parts,
+                sync_turret_emitters,
+                sync_core_armor,
+                handle_destroyed_parts,
+            )
+                .run_if(in_state(GameState::Playing))
+                .run_if(not_upgrading),
+        );
+    }
+}
+
+/// 装甲板存活时核心每次受击减免的伤害
+const CORE_ARMOR: i32 = 4;
+/// 击毁部件的奖励分数（不计经验）
+const PART_SCORE: u32 = 500;
+/// 炮塔基础开火间隔（秒），随 Boss 阶段缩短
+const TURRET_FIRE_INTERVAL: f32 = 1.8;
+const TURRET_BULLET_SPEED: f32 = 200.0;
+/// 炮口离炮塔中心的距离（沿开火方向）
+const TURRET_MUZZLE_OFFSET: f32 = 20.0;
+
+/// 部件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossPartKind {
+    /// 炮塔：独立瞄准玩家开火
+    Turret,
+    /// 装甲板：存活时核心获得护甲
+    ArmorPlate,
+}
+
+impl BossPartKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BossPartKind::Turret => "炮塔",
+            BossPartKind::ArmorPlate => "装甲",
+        }
+    }
+
+    /// 部件血量占 Boss 血量的比例
+    fn health_ratio(&self) -> f32 {
+        match self {
+            BossPartKind::Turret => 0.08,
+            BossPartKind::ArmorPlate => 0.12,
+        }
+    }
+}
+
+/// Boss 部件组件（独立实体，每帧跟随所属 Boss）
+#[derive(Component)]
+pub struct BossPart {
+    pub owner: Entity,
+    pub kind: BossPartKind,
+    /// 在 ActiveBoss::parts 中的序号
+    pub slot: usize,
+    /// 相对 Boss 中心的偏移（Boss 本地坐标）
+    pub offset: Vec2,
+}
+
+/// HUD 使用的部件状态
+#[derive(Debug, Clone, Copy)]
+pub struct BossPartStatus {
+    pub kind: BossPartKind,
+    pub alive: bool,
+}
+
+/// 各 Boss 的部件布局（类型, 偏移）；没有部件的 Boss 返回空
+pub fn boss_part_layout(boss_type: BossType) -> Vec<(BossPartKind, Vec2)> {
+    match boss_type {
+        BossType::DiamondKing => vec![
+            (BossPartKind::Turret, Vec2::new(-72.0, 0.0)),
+            (BossPartKind::Turret, Vec2::new(72.0, 0.0)),
+        ],
+        BossType::HexFortress => vec![
+            (BossPartKind::ArmorPlate, Vec2::new(-68.0, 0.0)),
+            (BossPartKind::ArmorPlate, Vec2::new(68.0, 0.0)),
+            (BossPartKind::Turret, Vec2::new(0.0, -70.0)),
+        ],
+        BossType::StarMothership => vec![
+            (BossPartKind::Turret, Vec2::new(-50.0, -52.0)),
+            (BossPartKind::Turret, Vec2::new(50.0, -52.0)),
+        ],
+        BossType::CircleGuardian => vec![
+            (BossPartKind::ArmorPlate, Vec2::new(0.0, -74.0)),
+            (BossPartKind::ArmorPlate, Vec2::new(-64.0, 37.0)),
+            (BossPartKind::ArmorPlate, Vec2::new(64.0, 37.0)),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+/// 为 Boss 生成全部部件（`scale` 与 Boss 体型缩放一致）
+pub fn spawn_boss_parts(
+    commands: &mut Commands,
+    owner: Entity,
+    boss_type: BossType,
+    position: Vec3,
+    boss_health: i32,
+    scale: f32,
+) {
+    let color = boss_type.color();
+    for (slot, (kind, offset)) in boss_part_layout(boss_type).into_iter().enumerate() {
+        let mut blueprint = part_blueprint(kind, color);
+        blueprint.scale *= scale;
+        let offset = offset * scale;
+        let pos = position + offset.extend(0.5);
+        let entity = spawn_geometry_entity(commands, &blueprint, pos);
+        let health = ((boss_health as f32 * kind.health_ratio()).ceil() as i32).max(1);
+
+        commands.entity(entity).insert((
+            BossPart {
+                owner,
+                kind,
+                slot,
+                offset,
+            },
+            Health::new(health),
+            Collider::new(blueprint.collision.clone(), CollisionLayer::ENEMY)
+                .with_mask(CollisionMask::enemy_mask()),
+        ));
+        if kind == BossPartKind::Turret {
+            let pattern = BulletPattern::Aimed {
+                count: 1,
+                spread: 0.0,
+                speed: TURRET_BULLET_SPEED,
+            };
+            // 错开各炮塔的首次开火
+            let emitter = BulletEmitter::new(
+                pattern,
+                BulletSource::Boss { damage: 1 },
+                TURRET_FIRE_INTERVAL,
+            )
+            .with_delay(TURRET_FIRE_INTERVAL + slot as f32 * 0.4);
+            commands.entity(entity).insert(BulletEmitter {
+                muzzle: TURRET_MUZZLE_OFFSET,
+                active: false,
+                ..emitter
+            });
+        }
+    }
+}
+
+/// 部件蓝图
+fn part_blueprint(kind: BossPartKind, color: ShapeColor) -> GeometryBlueprint {
+    match kind {
+        BossPartKind::Turret => GeometryBlueprint {
+            name: "boss_turret".to_string(),
+            shapes: vec![
+                GeometryShape::Circle {
+                    center: Vec2D::ZERO,
+                    radius: 14.0,
+                    color: ShapeColor::new(0.12, 0.14, 0.18, 0.95),
+                    fill: true,
+                    stroke_width: 2.0,
+                },
+                GeometryShape::Circle {
+                    center: Vec2D::ZERO,
+                    radius: 14.0,
+                    color,
+                    fill: false,
+                    stroke_width: 2.5,
+                },
+                // 炮管
+                GeometryShape::Line {
+                    start: Vec2D::ZERO,
+                    end: Vec2D::new(0.0, -22.0),
+                    color: ShapeColor::new(1.0, 0.85, 0.4, 0.9),
+                    stroke_width: 4.0,
+                },
+            ],
+            collision: CollisionShape::Circle { radius: 14.0 },
+            scale: 1.0,
+        },
+        BossPartKind::ArmorPlate => GeometryBlueprint {
+            name: "boss_armor_plate".to_string(),
+            shapes: vec![
+                GeometryShape::Polygon {
+                    vertices: vec![
+                        Vec2D::new(-18.0, -11.0),
+                        Vec2D::new(18.0, -11.0),
+                        Vec2D::new(22.0, 0.0),
+                        Vec2D::new(18.0, 11.0),
+                        Vec2D::new(-18.0, 11.0),
+                        Vec2D::new(-22.0, 0.0),
+                    ],
+                    color: ShapeColor::new(color.r * 0.6, color.g * 0.6, color.b * 0.6, 0.95),
+                    fill: true,
+                    stroke_width: 2.0,
+                },
+                GeometryShape::Line {
+                    start: Vec2D::new(-12.0, 0.0),
+                    end: Vec2D::new(12.0, 0.0),
+                    color: ShapeColor::new(1.0, 1.0, 1.0, 0.35),
+                    stroke_width: 2.0,
+                },
+            ],
+            collision: CollisionShape::OrientedBox {
+                width: 44.0,
+                height: 22.0,
+            },
+            scale: 1.0,
+        },
+    }
+}
+
+/// 部件跟随 Boss（随 Boss 旋转）；Boss 已不存在时一并移除
+fn follow_boss_parts(
+    mut commands: Commands,
+    bosses: Query<&Transform, With<Boss>>,
+    mut parts: Query<(Entity, &BossPart, &mut Transform), Without<Boss>>,
+) {
+    for (entity, part, mut transform) in parts.iter_mut() {
+        let Ok(boss_tf) = bosses.get(part.owner) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let offset = boss_tf.rotation * part.offset.extend(0.0);
+        transform.translation = boss_tf.translation + offset + Vec3::Z * 0.5;
+        transform.rotation = boss_tf.rotation;
+    }
+}
+
+/// 炮塔瞄准玩家开火（Boss 入场后、阶段转换期间暂停），阶段越高越快
+fn sync_turret_emitters(bosses: Query<&Boss>, mut turrets: Query<(&BossPart, &mut BulletEmitter)>) {
+    for (part, mut emitter) in turrets.iter_mut() {
+        let Ok(boss) = bosses.get(part.owner) else {
+            continue;
+        };
+        emitter.active = boss.entered && !boss.is_transitioning();
+        emitter.interval = TURRET_FIRE_INTERVAL / boss.phase.max(1) as f32;
+    }
+}
+
+/// 装甲板存活时为核心挂上护甲，全部被毁后移除（核心暴露）
+fn sync_core_armor(
+    mut commands: Commands,
+    bosses: Query<(Entity, Has<Armor>), With<Boss>>,
+    parts: Query<(&BossPart, &Health)>,
+) {
+    for (boss_entity, armored) in bosses.iter() {
+        let plates_alive = parts.iter().any(|(part, health)| {
+            part.owner == boss_entity && part.kind == BossPartKind::ArmorPlate && !health.is_dead()
+        });
+        if plates_alive && !armored {
+            commands.entity(boss_entity).try_insert(Armor(CORE_ARMOR));
+        } else if !plates_alive && armored {
+            commands.entity(boss_entity).remove::<Armor>();
+            log::info!("Boss core exposed");
+        }
+    }
+}
+
+/// 部件被击毁：爆炸特效、奖励分数、更新 HUD 状态
+fn handle_destroyed_parts(
+    mut commands: Commands,
+    mut destroyed: MessageReader<BossPartDestroyed>,
+    mut game_data: ResMut<GameData>,
+    mut boss_state: ResMut<BossState>,
+) {
+    for event in destroyed.read() {
+        spawn_rocket_explosion_particles(&mut commands, event.position, 14, 160.0);
+        game_data.add_score_only(PART_SCORE);
+        if let Some(status) = boss_state
+            .find_mut(event.boss)
+            .and_then(|boss| boss.parts.get_mut(event.slot))
+        {
+            status.alive = false;
+        }
+        log::info!("Boss part destroyed: {}", event.kind.name());
+    }
+}
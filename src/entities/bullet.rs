@@ -2,9 +2,15 @@
 
 use bevy::prelude::*;
 
-use crate::game::{not_upgrading, Collider, CollisionLayer, CollisionMask, GameConfig, GameState};
+use crate::game::{
+    not_upgrading, Collider, CollisionLayer, CollisionMask, GameConfig, GameState, SweptCollider,
+    SWEPT_SPEED_THRESHOLD,
+};
 use crate::geometry::{spawn_geometry_entity, GeometryBlueprint};
 
+use super::bullet_behavior::AlignToVelocity;
+use super::passives::StatModifiers;
+
 /// 敌人子弹样式
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EnemyBulletStyle {
@@ -36,6 +42,8 @@ pub struct Bullet {
     pub velocity: Vec2,
     pub damage: i32,
     pub is_player_bullet: bool,
+    /// 敌人子弹样式（分裂碎片沿用；玩家子弹为 None）
+    pub style: Option<EnemyBulletStyle>,
 }
 
 /// 射击冷却组件
@@ -46,18 +54,25 @@ pub struct ShootCooldown {
 }
 
 /// 生成玩家子弹
-pub fn spawn_player_bullet(commands: &mut Commands, position: Vec3, speed: f32) {
+pub fn spawn_player_bullet(
+    commands: &mut Commands,
+    position: Vec3,
+    speed: f32,
+    stats: &StatModifiers,
+) {
     let blueprint = GeometryBlueprint::default_bullet();
     let entity = spawn_geometry_entity(commands, &blueprint, position);
 
     commands.entity(entity).insert((
         Bullet {
-            velocity: Vec2::new(0.0, speed),
-            damage: 1,
+            velocity: Vec2::new(0.0, stats.scale_speed(speed)),
+            damage: stats.scale_damage(1),
             is_player_bullet: true,
+            style: None,
         },
-        Collider::new(blueprint.collision.clone(), CollisionLayer::PlayerBullet)
+        Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
             .with_mask(CollisionMask::player_bullet_mask()),
+        SweptCollider::default(),
     ));
 }
 
@@ -67,7 +82,7 @@ pub fn spawn_enemy_bullet(
     position: Vec3,
     velocity: Vec2,
     style: EnemyBulletStyle,
-) {
+) -> Entity {
     let blueprint = match style {
         EnemyBulletStyle::Shard => GeometryBlueprint::enemy_bullet(),
         EnemyBulletStyle::Needle => GeometryBlueprint::enemy_bullet_needle(),
@@ -75,15 +90,29 @@ pub fn spawn_enemy_bullet(
     };
     let entity = spawn_geometry_entity(commands, &blueprint, position);
 
+    // 针弹朝向飞行方向（针身沿本地 Y 轴），碰撞胶囊随之旋转
+    if style == EnemyBulletStyle::Needle && velocity != Vec2::ZERO {
+        let rotation = Quat::from_rotation_z(velocity.to_angle() - std::f32::consts::FRAC_PI_2);
+        commands.entity(entity).insert((
+            Transform::from_translation(position).with_rotation(rotation),
+            AlignToVelocity,
+        ));
+    }
+
     commands.entity(entity).insert((
         Bullet {
             velocity,
             damage: 1,
             is_player_bullet: false,
+            style: Some(style),
         },
-        Collider::new(blueprint.collision.clone(), CollisionLayer::EnemyBullet)
+        Collider::new(blueprint.collision.clone(), CollisionLayer::ENEMY_BULLET)
             .with_mask(CollisionMask::enemy_bullet_mask()),
     ));
+    if velocity.length() >= SWEPT_SPEED_THRESHOLD {
+        commands.entity(entity).insert(SweptCollider::default());
+    }
+    entity
 }
 
 /// 更新子弹位置
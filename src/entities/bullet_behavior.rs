@@ -0,0 +1,259 @@
+//! 子弹行为
+//! 可组合的子弹行为组件：加速/减速、弧线转向、延时重新瞄准、定时分裂、屏幕边缘反弹；
+//! 敌人子弹（Bullet）与 Boss 子弹（BossBullet）通用
+
+use bevy::ecs::component::Mutable;
+use bevy::prelude::*;
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use crate::game::{not_upgrading, GameConfig, GameState, SweptCollider, SWEPT_SPEED_THRESHOLD};
+
+use super::boss::{spawn_boss_bullet, BossBullet};
+use super::bullet::{spawn_enemy_bullet, Bullet, EnemyBulletStyle};
+use super::Player;
+
+/// 子弹行为插件
+pub struct BulletBehaviorPlugin;
+
+impl Plugin for BulletBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                accelerate_bullets::<Bullet>,
+                accelerate_bullets::<BossBullet>,
+                curve_bullets::<Bullet>,
+                curve_bullets::<BossBullet>,
+                reaim_bullets::<Bullet>,
+                reaim_bullets::<BossBullet>,
+                bounce_bullets::<Bullet>,
+                bounce_bullets::<BossBullet>,
+                split_bullets::<Bullet>,
+                split_bullets::<BossBullet>,
+                align_bullets::<Bullet>,
+                align_bullets::<BossBullet>,
+            )
+                .run_if(in_state(GameState::Playing))
+                .run_if(not_upgrading),
+        );
+    }
+}
+
+/// 可挂载行为组件的子弹
+pub trait BehaviorBullet: Component<Mutability = Mutable> {
+    fn velocity(&self) -> Vec2;
+    fn set_velocity(&mut self, velocity: Vec2);
+    /// 分裂时生成一枚同类子弹
+    fn spawn_fragment(&self, commands: &mut Commands, position: Vec3, velocity: Vec2) -> Entity;
+}
+
+impl BehaviorBullet for Bullet {
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
+    fn spawn_fragment(&self, commands: &mut Commands, position: Vec3, velocity: Vec2) -> Entity {
+        let style = self.style.unwrap_or(EnemyBulletStyle::Ring);
+        spawn_enemy_bullet(commands, position, velocity, style)
+    }
+}
+
+impl BehaviorBullet for BossBullet {
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
+    fn spawn_fragment(&self, commands: &mut Commands, position: Vec3, velocity: Vec2) -> Entity {
+        spawn_boss_bullet(commands, position, velocity, self.damage)
+    }
+}
+
+/// 加速/减速：速率以 `acceleration` 逐渐趋向 `target_speed`（方向不变）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BulletAcceleration {
+    pub acceleration: f32,
+    pub target_speed: f32,
+}
+
+/// 弧线：速度方向以 `angular_velocity`（弧度/秒，正值逆时针）旋转，持续 `duration` 秒
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BulletCurve {
+    pub angular_velocity: f32,
+    pub duration: f32,
+}
+
+/// 延时重新瞄准：`delay` 秒后转向玩家当前位置，速率变为 `speed`（只触发一次）；
+/// 与加速度组合时可做“减速悬停 → 重新瞄准”，瞄准时会移除加速度
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BulletReaim {
+    pub delay: f32,
+    pub speed: f32,
+}
+
+/// 定时分裂：`timer` 秒后消失并分裂为 `count` 枚子弹
+/// `spread` 为以当前飞行方向为中心的扇形角度（≥ 2π 时为整圈环形）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BulletSplit {
+    pub timer: f32,
+    pub count: u32,
+    pub spread: f32,
+    pub speed: f32,
+}
+
+/// 屏幕边缘反弹（左右与上边缘），剩余 `remaining` 次
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BulletBounce {
+    pub remaining: u32,
+}
+
+/// 朝向跟随飞行方向（针弹等细长子弹，图形沿本地 Y 轴）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AlignToVelocity;
+
+/// 行为把子弹提速到扫掠阈值以上时补上扫掠碰撞，避免高速穿过玩家
+fn ensure_swept(commands: &mut Commands, entity: Entity, speed: f32, swept: bool) {
+    if !swept && speed >= SWEPT_SPEED_THRESHOLD {
+        commands.entity(entity).try_insert(SweptCollider::default());
+    }
+}
+
+fn accelerate_bullets<T: BehaviorBullet>(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut T, &BulletAcceleration, Has<SweptCollider>)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut bullet, accel, swept) in query.iter_mut() {
+        let velocity = bullet.velocity();
+        let speed = velocity.length();
+        if speed == accel.target_speed {
+            continue;
+        }
+        let step = accel.acceleration.abs() * delta;
+        let new_speed = if speed < accel.target_speed {
+            (speed + step).min(accel.target_speed)
+        } else {
+            (speed - step).max(accel.target_speed)
+        };
+        bullet.set_velocity(velocity.normalize_or(Vec2::NEG_Y) * new_speed);
+        ensure_swept(&mut commands, entity, new_speed, swept);
+    }
+}
+
+fn curve_bullets<T: BehaviorBullet>(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut T, &mut BulletCurve)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut bullet, mut curve) in query.iter_mut() {
+        let rotated = Vec2::from_angle(curve.angular_velocity * delta).rotate(bullet.velocity());
+        bullet.set_velocity(rotated);
+        curve.duration -= delta;
+        if curve.duration <= 0.0 {
+            commands.entity(entity).remove::<BulletCurve>();
+        }
+    }
+}
+
+fn reaim_bullets<T: BehaviorBullet>(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_query: Query<&Transform, With<Player>>,
+    mut query: Query<(Entity, &mut T, &Transform, &mut BulletReaim), Without<Player>>,
+    swept: Query<(), With<SweptCollider>>,
+) {
+    let player_pos = player_query
+        .single()
+        .ok()
+        .map(|tf| tf.translation.truncate());
+    for (entity, mut bullet, transform, mut reaim) in query.iter_mut() {
+        reaim.delay -= time.delta_secs();
+        if reaim.delay > 0.0 {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .remove::<(BulletReaim, BulletAcceleration)>();
+        // 玩家不在场时沿原方向继续
+        let direction = player_pos.map_or(bullet.velocity().normalize_or(Vec2::NEG_Y), |p| {
+            (p - transform.translation.truncate()).normalize_or(Vec2::NEG_Y)
+        });
+        bullet.set_velocity(direction * reaim.speed);
+        ensure_swept(&mut commands, entity, reaim.speed, swept.contains(entity));
+    }
+}
+
+fn bounce_bullets<T: BehaviorBullet>(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    mut query: Query<(Entity, &mut T, &Transform, &mut BulletBounce)>,
+) {
+    let half_w = config.window_width / 2.0;
+    let half_h = config.window_height / 2.0;
+    for (entity, mut bullet, transform, mut bounce) in query.iter_mut() {
+        let pos = transform.translation;
+        let mut velocity = bullet.velocity();
+        let hit_side = pos.x.abs() >= half_w && pos.x * velocity.x > 0.0;
+        let hit_top = pos.y >= half_h && velocity.y > 0.0;
+        if !hit_side && !hit_top {
+            continue;
+        }
+        if hit_side {
+            velocity.x = -velocity.x;
+        }
+        if hit_top {
+            velocity.y = -velocity.y;
+        }
+        bullet.set_velocity(velocity);
+        bounce.remaining = bounce.remaining.saturating_sub(1);
+        if bounce.remaining == 0 {
+            commands.entity(entity).remove::<BulletBounce>();
+        }
+    }
+}
+
+fn split_bullets<T: BehaviorBullet>(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &T, &Transform, &mut BulletSplit)>,
+) {
+    for (entity, bullet, transform, mut split) in query.iter_mut() {
+        split.timer -= time.delta_secs();
+        if split.timer > 0.0 {
+            continue;
+        }
+        commands.entity(entity).despawn();
+
+        let heading = bullet.velocity().to_angle();
+        let count = split.count.max(1);
+        for i in 0..count {
+            let angle = if count == 1 {
+                heading
+            } else if split.spread >= TAU {
+                heading + TAU * i as f32 / count as f32
+            } else {
+                heading + split.spread * (i as f32 / (count - 1) as f32 - 0.5)
+            };
+            let velocity = Vec2::from_angle(angle) * split.speed;
+            bullet.spawn_fragment(&mut commands, transform.translation, velocity);
+        }
+    }
+}
+
+fn align_bullets<T: BehaviorBullet>(mut query: Query<(&T, &mut Transform), With<AlignToVelocity>>) {
+    for (bullet, mut transform) in query.iter_mut() {
+        let velocity = bullet.velocity();
+        if velocity != Vec2::ZERO {
+            transform.rotation = Quat::from_rotation_z(velocity.to_angle() - FRAC_PI_2);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! 弹幕模式库
+//! 瞄准、扇形、环形、螺旋、随机散射、直线连射等常用弹幕的统一实现，
+//! 敌人、精英、Boss 直接调用 `fire_pattern`，炮塔等持续开火的实体挂 `BulletEmitter`
+
+use bevy::prelude::*;
+use rand::Rng;
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use crate::game::{not_upgrading, GameState};
+
+use super::boss::spawn_boss_bullet;
+use super::bullet::{spawn_enemy_bullet, EnemyBulletStyle};
+use super::Player;
+
+/// 弹幕发射器插件
+pub struct BulletPatternPlugin;
+
+impl Plugin for BulletPatternPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            update_bullet_emitters
+                .run_if(in_state(GameState::Playing))
+                .run_if(not_upgrading),
+        );
+    }
+}
+
+/// 弹幕模式（角度单位为弧度，0 为正右方，-π/2 为正下方）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BulletPattern {
+    /// 瞄准玩家的扇形（count 为 1 时为单发）；玩家不在场时朝正下方
+    Aimed { count: u32, spread: f32, speed: f32 },
+    /// 固定方向的扇形，`spread` 为两端子弹的总夹角
+    Fan {
+        angle: f32,
+        count: u32,
+        spread: f32,
+        speed: f32,
+    },
+    /// 整圈环形，`offset` 为第一发的角度
+    Ring { count: u32, speed: f32, offset: f32 },
+    /// 螺旋：环形弹每次发射整体旋转 `step`
+    Spiral { arms: u32, speed: f32, step: f32 },
+    /// 在 `angle` 两侧 `spread` 范围内随机散射，速率随机
+    Spray {
+        angle: f32,
+        spread: f32,
+        count: u32,
+        min_speed: f32,
+        max_speed: f32,
+    },
+    /// 沿同一方向排成一列的连射弹（`angle` 为 None 时瞄准玩家）
+    Stream {
+        angle: Option<f32>,
+        count: u32,
+        spacing: f32,
+        speed: f32,
+    },
+}
+
+/// 正下方
+pub const DOWN: f32 = -FRAC_PI_2;
+
+/// 子弹来源（决定子弹外观与伤害）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BulletSource {
+    Enemy(EnemyBulletStyle),
+    Boss { damage: i32 },
+}
+
+impl BulletSource {
+    pub fn spawn(self, commands: &mut Commands, position: Vec3, velocity: Vec2) -> Entity {
+        match self {
+            BulletSource::Enemy(style) => spawn_enemy_bullet(commands, position, velocity, style),
+            BulletSource::Boss { damage } => {
+                spawn_boss_bullet(commands, position, velocity, damage)
+            }
+        }
+    }
+}
+
+/// 扇形各发的角度（两端对称分布）
+fn fan_angles(center: f32, count: u32, spread: f32) -> impl Iterator<Item = f32> {
+    (0..count).map(move |i| {
+        if count <= 1 {
+            center
+        } else {
+            center + spread * (i as f32 / (count - 1) as f32 - 0.5)
+        }
+    })
+}
+
+/// 环形各发的角度
+fn ring_angles(offset: f32, count: u32) -> impl Iterator<Item = f32> {
+    (0..count).map(move |i| offset + TAU * i as f32 / count as f32)
+}
+
+/// 按模式发射一轮子弹，返回生成的子弹（可再挂上子弹行为组件）
+/// `target` 为瞄准目标（通常是玩家位置），`turn` 为已发射轮数（螺旋旋转用）
+pub fn fire_pattern(
+    commands: &mut Commands,
+    pattern: &BulletPattern,
+    source: BulletSource,
+    origin: Vec3,
+    target: Option<Vec2>,
+    turn: u32,
+) -> Vec<Entity> {
+    fire_pattern_from_muzzle(commands, pattern, source, origin, target, turn, 0.0)
+}
+
+/// 同 [`fire_pattern`]，但每发子弹从沿各自发射方向离 `origin` `muzzle` 像素处出膛
+/// （瞄准仍以 `origin` 为准），用于炮管等有长度的发射口
+pub fn fire_pattern_from_muzzle(
+    commands: &mut Commands,
+    pattern: &BulletPattern,
+    source: BulletSource,
+    origin: Vec3,
+    target: Option<Vec2>,
+    turn: u32,
+    muzzle: f32,
+) -> Vec<Entity> {
+    let aim = target
+        .map(|t| (t - origin.truncate()).normalize_or(Vec2::NEG_Y).to_angle())
+        .unwrap_or(DOWN);
+    let mut spawn = |angle: f32, speed: f32, position: Vec3| {
+        let direction = Vec2::from_angle(angle);
+        let position = position + (direction * muzzle).extend(0.0);
+        source.spawn(commands, position, direction * speed)
+    };
+
+    match *pattern {
+        BulletPattern::Aimed {
+            count,
+            spread,
+            speed,
+        } => fan_angles(aim, count, spread)
+            .map(|angle| spawn(angle, speed, origin))
+            .collect(),
+        BulletPattern::Fan {
+            angle,
+            count,
+            spread,
+            speed,
+        } => fan_angles(angle, count, spread)
+            .map(|angle| spawn(angle, speed, origin))
+            .collect(),
+        BulletPattern::Ring {
+            count,
+            speed,
+            offset,
+        } => ring_angles(offset, count)
+            .map(|angle| spawn(angle, speed, origin))
+            .collect(),
+        BulletPattern::Spiral { arms, speed, step } => ring_angles(turn as f32 * step, arms)
+            .map(|angle| spawn(angle, speed, origin))
+            .collect(),
+        BulletPattern::Spray {
+            angle,
+            spread,
+            count,
+            min_speed,
+            max_speed,
+        } => {
+            let mut rng = rand::rng();
+            (0..count)
+                .map(|_| {
+                    let angle = angle + rng.random_range(-spread / 2.0..=spread / 2.0);
+                    let speed = rng.random_range(min_speed..=max_speed);
+                    spawn(angle, speed, origin)
+                })
+                .collect()
+        }
+        BulletPattern::Stream {
+            angle,
+            count,
+            spacing,
+            speed,
+        } => {
+            let angle = angle.unwrap_or(aim);
+            let direction = Vec2::from_angle(angle);
+            (0..count)
+                .map(|i| {
+                    let position = origin + (direction * i as f32 * spacing).extend(0.0);
+                    spawn(angle, speed, position)
+                })
+                .collect()
+        }
+    }
+}
+
+/// 弹幕发射器：按间隔持续发射同一模式（炮塔、场景机关等）
+#[derive(Component, Debug, Clone)]
+pub struct BulletEmitter {
+    pub pattern: BulletPattern,
+    pub source: BulletSource,
+    /// 发射间隔（秒）
+    pub interval: f32,
+    /// 距下次发射的时间
+    pub timer: f32,
+    /// 出膛点沿每发子弹的发射方向离实体中心的距离
+    pub muzzle: f32,
+    /// 为 false 时暂停发射（计时也暂停）
+    pub active: bool,
+    /// 已发射轮数
+    pub turn: u32,
+}
+
+impl BulletEmitter {
+    pub fn new(pattern: BulletPattern, source: BulletSource, interval: f32) -> Self {
+        Self {
+            pattern,
+            source,
+            interval,
+            timer: interval,
+            muzzle: 0.0,
+            active: true,
+            turn: 0,
+        }
+    }
+
+    /// 首次发射前的延迟
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.timer = delay;
+        self
+    }
+}
+
+/// 发射器计时与开火
+fn update_bullet_emitters(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_query: Query<&Transform, With<Player>>,
+    mut emitters: Query<(&Transform, &mut BulletEmitter), Without<Player>>,
+) {
+    let player_pos = player_query
+        .single()
+        .ok()
+        .map(|tf| tf.translation.truncate());
+
+    for (transform, mut emitter) in emitters.iter_mut() {
+        if !emitter.active {
+            continue;
+        }
+        emitter.timer -= time.delta_secs();
+        if emitter.timer > 0.0 {
+            continue;
+        }
+        emitter.timer = emitter.interval;
+
+        fire_pattern_from_muzzle(
+            &mut commands,
+            &emitter.pattern,
+            emitter.source,
+            transform.translation,
+            player_pos,
+            emitter.turn,
+            emitter.muzzle,
+        );
+        emitter.turn += 1;
+    }
+}
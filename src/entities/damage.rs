@@ -0,0 +1,384 @@
+//! 伤害系统
+//! 统一的生命值组件与伤害消息：命中处理只负责产生 DamageEvent，
+//! 由一个结算系统负责弱点、护甲、扣血、死亡、得分与掉落
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::game::{not_upgrading, CollisionEvent, CollisionLayer, GameData, GameState, WeakPoints};
+
+use super::boss_mechanics::{spawn_split_cores, BossSplit};
+use super::boss_parts::BossPart;
+use super::events::{BossDefeated, BossPartDestroyed, EnemyKilled, GameplayEvents};
+use super::passives::StatModifiers;
+use super::shield::{spawn_power_up, PowerUpType};
+use super::weapons::{
+    spawn_boss_hit_flash, spawn_hit_sparks, spawn_rocket_explosion_particles, HitList, Pierce,
+    RocketBullet, WeaponBullet, WeaponType,
+};
+use super::{Boss, BossState, Bullet, Enemy, Player};
+
+/// 伤害插件
+pub struct DamagePlugin;
+
+impl Plugin for DamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<DamageEvent>().add_systems(
+            Update,
+            (
+                player_projectile_hits,
+                resolve_damage.after(player_projectile_hits),
+            )
+                .run_if(in_state(GameState::Playing))
+                .run_if(not_upgrading),
+        );
+    }
+}
+
+/// 生命值组件：敌人、Boss 等可受伤实体通用
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Health {
+    pub current: i32,
+    pub max: i32,
+}
+
+impl Health {
+    pub fn new(max: i32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// 剩余生命比例（0~1）
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0 {
+            return 0.0;
+        }
+        (self.current as f32 / self.max as f32).clamp(0.0, 1.0)
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.current <= 0
+    }
+}
+
+/// 无敌：受击只有火花，不扣血（护盾相位等）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Invulnerable;
+
+/// 护甲：每次受击减免固定伤害（至少仍造成 1 点）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Armor(pub i32);
+
+/// 伤害消息
+#[derive(Message, Debug, Clone)]
+pub struct DamageEvent {
+    /// 伤害来源（子弹、闪电链等；结算时可能已被销毁）
+    pub source: Option<Entity>,
+    pub target: Entity,
+    pub amount: i32,
+    /// 武器类型（基础子弹为 None）
+    pub weapon: Option<WeaponType>,
+    /// 命中位置（火花、掉落物）
+    pub position: Vec3,
+    /// 命中的复合碰撞箱子形状，用于弱点倍率
+    pub sub_shape: Option<usize>,
+}
+
+/// 伤害结算读取的目标数据
+#[derive(SystemParam)]
+struct DamageTargets<'w, 's> {
+    health: Query<
+        'w,
+        's,
+        (
+            &'static mut Health,
+            Option<&'static Armor>,
+            Option<&'static WeakPoints>,
+        ),
+    >,
+    kinds: Query<
+        'w,
+        's,
+        (
+            &'static Transform,
+            Option<&'static Enemy>,
+            Option<&'static Boss>,
+        ),
+    >,
+    parts: Query<'w, 's, &'static BossPart>,
+    splits: Query<'w, 's, &'static BossSplit>,
+    invulnerable: Query<'w, 's, (), With<Invulnerable>>,
+}
+
+/// 玩家弹体命中：处理穿透、HitList 去重与导弹爆炸，产生伤害消息
+fn player_projectile_hits(
+    mut commands: Commands,
+    mut collision_events: MessageReader<CollisionEvent>,
+    mut damage_events: MessageWriter<DamageEvent>,
+    targets: Query<(Entity, &Transform), With<Health>>,
+    bullets: Query<(&Transform, &Bullet)>,
+    weapon_bullets: Query<(&Transform, &WeaponBullet, Option<&RocketBullet>)>,
+    mut projectile_state: Query<(Option<&mut Pierce>, Option<&mut HitList>)>,
+) {
+    // 本帧已销毁的弹体（同一帧可能同时命中多个目标）
+    let mut spent: Vec<Entity> = Vec::new();
+
+    for event in collision_events.read() {
+        let (projectile, target, sub_shape) = if event.layer_a == CollisionLayer::PLAYER_BULLET
+            && event.layer_b == CollisionLayer::ENEMY
+        {
+            (event.entity_a, event.entity_b, event.sub_shape_b)
+        } else if event.layer_b == CollisionLayer::PLAYER_BULLET
+            && event.layer_a == CollisionLayer::ENEMY
+        {
+            (event.entity_b, event.entity_a, event.sub_shape_a)
+        } else {
+            continue;
+        };
+
+        if spent.contains(&projectile) {
+            continue;
+        }
+        let Ok((_, target_tf)) = targets.get(target) else {
+            continue;
+        };
+        let target_pos = target_tf.translation;
+
+        // 基础子弹：单体伤害后销毁
+        if let Ok((bullet_tf, bullet)) = bullets.get(projectile) {
+            damage_events.write(DamageEvent {
+                source: Some(projectile),
+                target,
+                amount: bullet.damage,
+                weapon: None,
+                position: bullet_tf.translation,
+                sub_shape,
+            });
+            commands.entity(projectile).despawn();
+            spent.push(projectile);
+            continue;
+        }
+
+        let Ok((projectile_tf, weapon_bullet, rocket)) = weapon_bullets.get(projectile) else {
+            continue;
+        };
+        let projectile_pos = projectile_tf.translation;
+        let Ok((pierce, hit_list)) = projectile_state.get_mut(projectile) else {
+            continue;
+        };
+
+        // 避免穿透/持续类武器在连续帧对同一目标反复结算
+        if let Some(mut hits) = hit_list {
+            if hits.entities.contains(&target) {
+                continue;
+            }
+            hits.entities.push(target);
+        }
+
+        // 导弹（含蜂群导弹）：命中立刻爆炸（AOE），范围内目标全部受伤
+        if let Some(rocket) = rocket {
+            let center = projectile_pos.truncate();
+            let r2 = rocket.explosion_radius * rocket.explosion_radius;
+            for (entity, tf) in targets.iter() {
+                let in_range = tf.translation.truncate().distance_squared(center) <= r2;
+                if entity != target && !in_range {
+                    continue;
+                }
+                damage_events.write(DamageEvent {
+                    source: Some(projectile),
+                    target: entity,
+                    amount: weapon_bullet.damage,
+                    weapon: Some(weapon_bullet.weapon_type),
+                    position: tf.translation,
+                    sub_shape: (entity == target).then_some(sub_shape).flatten(),
+                });
+            }
+            let shard_count = ((rocket.explosion_radius / 4.0) as u32).clamp(10, 28);
+            spawn_rocket_explosion_particles(
+                &mut commands,
+                projectile_pos,
+                shard_count,
+                rocket.speed,
+            );
+            commands.entity(projectile).despawn();
+            spent.push(projectile);
+            continue;
+        }
+
+        // 其它武器：单体伤害
+        // 命中位置：能量波这类“面积武器”用目标位置更符合观感
+        let position = match weapon_bullet.weapon_type {
+            WeaponType::Beam => target_pos,
+            _ => projectile_pos,
+        };
+        damage_events.write(DamageEvent {
+            source: Some(projectile),
+            target,
+            amount: weapon_bullet.damage,
+            weapon: Some(weapon_bullet.weapon_type),
+            position,
+            sub_shape,
+        });
+
+        // 是否需要销毁弹体（穿透则保留）
+        let mut should_despawn = true;
+        if let Some(mut p) = pierce {
+            if p.remaining == u32::MAX {
+                should_despawn = false;
+            } else if p.remaining > 1 {
+                p.remaining -= 1;
+                should_despawn = false;
+            } else {
+                p.remaining = 0;
+            }
+        }
+        if should_despawn {
+            commands.entity(projectile).despawn();
+            spent.push(projectile);
+        }
+    }
+}
+
+/// 伤害结算：无敌 → 弱点倍率 → 护甲 → 扣血 → 死亡、得分与掉落
+fn resolve_damage(
+    mut commands: Commands,
+    mut damage_events: MessageReader<DamageEvent>,
+    mut game_data: ResMut<GameData>,
+    mut boss_state: ResMut<BossState>,
+    mut targets: DamageTargets,
+    mut events: GameplayEvents,
+    players: Query<&StatModifiers, With<Player>>,
+) {
+    let luck = players.single().map_or(1.0, |stats| stats.luck);
+    for event in damage_events.read() {
+        let Ok((mut health, armor, weak_points)) = targets.health.get_mut(event.target) else {
+            continue;
+        };
+        let Ok((transform, enemy, boss)) = targets.kinds.get(event.target) else {
+            continue;
+        };
+        let part = targets.parts.get(event.target).ok();
+        // 同一帧内已被击毁（实体尚未真正移除）
+        if health.is_dead() {
+            continue;
+        }
+        // 无敌（护盾相位、Boss 阶段转换）：只有火花
+        let transitioning = boss.is_some_and(Boss::is_transitioning);
+        if transitioning || targets.invulnerable.contains(event.target) {
+            spawn_hit_sparks(&mut commands, event.position);
+            continue;
+        }
+
+        let multiplier = weak_points.map_or(1.0, |w| w.multiplier(event.sub_shape));
+        let mut amount = (event.amount as f32 * multiplier).round() as i32;
+        if let Some(armor) = armor {
+            amount = (amount - armor.0).max(1);
+        }
+        let applied = amount.min(health.current);
+        health.current -= amount;
+
+        // 命中火花（导弹/感应雷自带爆炸特效，激光自带命中火花）；命中弱点追加一次
+        let own_effect = matches!(
+            event.weapon,
+            Some(
+                WeaponType::Rocket
+                    | WeaponType::SwarmMissiles
+                    | WeaponType::Mine
+                    | WeaponType::Laser
+                    | WeaponType::ArcLaser
+            )
+        );
+        if !own_effect {
+            spawn_hit_sparks(&mut commands, event.position);
+        }
+        if multiplier > 1.0 {
+            spawn_hit_sparks(&mut commands, event.position);
+        }
+        if boss.is_some() || part.is_some() {
+            spawn_boss_hit_flash(&mut commands, event.position);
+        }
+        if boss.is_some() {
+            // 多实体 Boss（分裂核心）共享一条总血量
+            if let Some(active) = boss_state.find_mut(event.target) {
+                active.current_health = (active.current_health - applied).max(0);
+            }
+        }
+
+        if !health.is_dead() {
+            continue;
+        }
+        commands.entity(event.target).despawn();
+        let position = transform.translation;
+
+        if let Some(boss) = boss {
+            let score = boss.score_value;
+            game_data.add_kill_score(score);
+            let children = match targets.splits.get(event.target) {
+                Ok(split) => spawn_split_cores(&mut commands, boss, *split, position, health.max),
+                Err(_) => Vec::new(),
+            };
+            let Some(index) = boss_state
+                .bosses
+                .iter()
+                .position(|b| b.entities.contains(&event.target))
+            else {
+                continue;
+            };
+            let active = &mut boss_state.bosses[index];
+            active.entities.retain(|e| *e != event.target);
+            active.entities.extend(children);
+            // 该 Boss 的所有实体都被击毁才算击败；全部 Boss 被击败后战斗结束
+            if active.entities.is_empty() {
+                let mid_boss = boss_state.bosses.remove(index).mid_boss;
+                let final_boss = boss_state.bosses.is_empty();
+                if final_boss {
+                    boss_state.active = false;
+                }
+                events.boss_defeated.write(BossDefeated {
+                    entity: event.target,
+                    boss_type: boss.boss_type,
+                    position,
+                    score,
+                    mid_boss,
+                    final_boss,
+                });
+                log::info!("Boss defeated! Score: {}", score);
+            }
+        } else if let Some(part) = part {
+            events.boss_part_destroyed.write(BossPartDestroyed {
+                boss: part.owner,
+                part: event.target,
+                kind: part.kind,
+                slot: part.slot,
+                position,
+            });
+        } else if let Some(enemy) = enemy {
+            if boss_state.active {
+                // Boss 战期间的小兵：不增长经验、无掉落
+                game_data.add_kill_score_only(enemy.score_value);
+            } else {
+                game_data.add_kill_score(enemy.score_value);
+                spawn_kill_drop(&mut commands, position, luck);
+            }
+            events.enemy_killed.write(EnemyKilled {
+                entity: event.target,
+                enemy_type: enemy.enemy_type,
+                position,
+                score: enemy.score_value,
+                weapon: event.weapon,
+            });
+        }
+    }
+}
+
+/// 击杀掉落：心(0.5%) / 盾(1%) / 金币(2%)，最多掉 1 个；概率按幸运倍率放大
+fn spawn_kill_drop(commands: &mut Commands, position: Vec3, luck: f32) {
+    let roll = rand::rng().random_range(0.0..1.0) / luck;
+    if roll < 0.005 {
+        spawn_power_up(commands, position, PowerUpType::ExtraLife);
+    } else if roll < 0.015 {
+        spawn_power_up(commands, position, PowerUpType::Shield);
+    } else if roll < 0.035 {
+        spawn_power_up(commands, position, PowerUpType::Coin);
+    }
+}
@@ -4,12 +4,13 @@ use bevy::prelude::*;
 use rand::Rng;
 
 use crate::entities::{
-    spawn_rocket_explosion_particles, BossState, Bullet, HitList, Pierce, RocketBullet,
-    WeaponBullet, WeaponType,
+    fire_pattern, spawn_telegraph_line, BossState, BulletAcceleration, BulletCurve, BulletPattern,
+    BulletReaim, BulletSource, EnemyBulletStyle, Health, Player, TelegraphFollow, TelegraphShot,
+    DOWN,
 };
 use crate::game::{
-    not_upgrading, Collider, CollisionEvent, CollisionLayer, CollisionMask, GameConfig, GameData,
-    GameState, Scrollable,
+    not_upgrading, Collider, CollisionLayer, CollisionMask, GameConfig, GameData, GameState,
+    Scrollable,
 };
 use crate::geometry::{spawn_geometry_entity, GeometryBlueprint};
 
@@ -28,7 +29,6 @@ impl Plugin for EnemyPlugin {
                     spawn_enemies,
                     enemy_movement,
                     enemy_shooting,
-                    enemy_collision_handler,
                     despawn_offscreen_enemies,
                 )
                     .run_if(in_state(GameState::Playing))
@@ -40,8 +40,6 @@ impl Plugin for EnemyPlugin {
 /// 敌人组件
 #[derive(Component)]
 pub struct Enemy {
-    pub health: i32,
-    pub max_health: i32,
     pub score_value: u32,
     pub enemy_type: EnemyType,
     pub shoot_timer: f32,
@@ -204,8 +202,8 @@ pub fn spawn_enemy(
     config: &GameConfig,
     position: Vec3,
     enemy_type: EnemyType,
-) {
-    spawn_enemy_with_difficulty(commands, config, position, enemy_type, 1.0);
+) -> Entity {
+    spawn_enemy_with_difficulty(commands, config, position, enemy_type, 1.0)
 }
 
 /// 生成带难度系数的敌人
@@ -215,7 +213,7 @@ pub fn spawn_enemy_with_difficulty(
     position: Vec3,
     enemy_type: EnemyType,
     difficulty: f32,
-) {
+) -> Entity {
     let mut rng = rand::rng();
 
     // 基础属性
@@ -280,18 +278,18 @@ pub fn spawn_enemy_with_difficulty(
 
     commands.entity(entity).insert((
         Enemy {
-            health,
-            max_health: health,
             score_value: score,
             enemy_type,
             shoot_timer: rng.random_range(0.0..shoot_interval),
             shoot_interval,
         },
+        Health::new(health),
         movement,
-        Collider::new(blueprint.collision.clone(), CollisionLayer::Enemy)
+        Collider::new(blueprint.collision.clone(), CollisionLayer::ENEMY)
             .with_mask(CollisionMask::enemy_mask()),
         Scrollable::default(),
     ));
+    entity
 }
 
 /// 敌人移动
@@ -328,14 +326,23 @@ fn enemy_movement(time: Res<Time>, mut query: Query<(&mut Transform, &mut EnemyM
     }
 }
 
+/// 精英狙击弹的预警时间
+const SNIPER_LEAD: f32 = 0.6;
+
 /// 敌人射击
 fn enemy_shooting(
     mut commands: Commands,
     time: Res<Time>,
     config: Res<GameConfig>,
-    mut query: Query<(&Transform, &mut Enemy)>,
+    mut query: Query<(Entity, &Transform, &mut Enemy)>,
+    player_query: Query<&Transform, With<Player>>,
 ) {
-    for (transform, mut enemy) in query.iter_mut() {
+    let player_pos = player_query
+        .single()
+        .ok()
+        .map(|tf| tf.translation.truncate());
+
+    for (entity, transform, mut enemy) in query.iter_mut() {
         enemy.shoot_timer -= time.delta_secs();
 
         if enemy.shoot_timer <= 0.0 {
@@ -347,295 +354,148 @@ fn enemy_shooting(
             // 精英：慢速但弹幕更密
             match enemy.enemy_type {
                 EnemyType::EliteScout => {
-                    // 3-way 扇形 + 1 个中心加速弹
-                    let base = -std::f32::consts::FRAC_PI_2;
-                    for (i, speed_mul, style) in [
-                        (-1, 0.55, super::bullet::EnemyBulletStyle::Ring),
-                        (0, 0.65, super::bullet::EnemyBulletStyle::Shard),
-                        (1, 0.55, super::bullet::EnemyBulletStyle::Ring),
-                        (0, 0.85, super::bullet::EnemyBulletStyle::Needle),
-                    ] {
-                        let angle = base + (i as f32) * 0.18;
-                        let v = Vec2::new(angle.cos(), angle.sin()) * (config.bullet_speed * speed_mul);
-                        super::bullet::spawn_enemy_bullet(&mut commands, bullet_pos, v, style);
-                    }
+                    // 3-way 扇形 + 1 发预警后的狙击针弹
+                    let sides = BulletPattern::Fan {
+                        angle: DOWN,
+                        count: 2,
+                        spread: 0.36,
+                        speed: config.bullet_speed * 0.55,
+                    };
+                    let center = BulletPattern::Fan {
+                        angle: DOWN,
+                        count: 1,
+                        spread: 0.0,
+                        speed: config.bullet_speed * 0.65,
+                    };
+                    fire_pattern(
+                        &mut commands,
+                        &sides,
+                        BulletSource::Enemy(EnemyBulletStyle::Ring),
+                        bullet_pos,
+                        None,
+                        0,
+                    );
+                    fire_pattern(
+                        &mut commands,
+                        &center,
+                        BulletSource::Enemy(EnemyBulletStyle::Shard),
+                        bullet_pos,
+                        None,
+                        0,
+                    );
+                    // 狙击针弹：锁定玩家当前位置，预警后高速射出
+                    let angle = player_pos
+                        .map(|p| (p - bullet_pos.truncate()).to_angle())
+                        .unwrap_or(DOWN);
+                    let telegraph = spawn_telegraph_line(
+                        &mut commands,
+                        bullet_pos,
+                        angle,
+                        config.window_height,
+                        SNIPER_LEAD,
+                    );
+                    commands.entity(telegraph).insert((
+                        TelegraphShot {
+                            pattern: BulletPattern::Fan {
+                                angle,
+                                count: 1,
+                                spread: 0.0,
+                                speed: config.bullet_speed * 1.1,
+                            },
+                            source: BulletSource::Enemy(EnemyBulletStyle::Needle),
+                        },
+                        TelegraphFollow {
+                            owner: entity,
+                            offset: Vec2::new(0.0, -24.0),
+                        },
+                    ));
                 }
                 EnemyType::EliteGunship => {
                     // 5-way 扇形（更宽）+ 两侧“压制弹”
-                    let base = -std::f32::consts::FRAC_PI_2;
-                    let count = 5;
-                    let spread = 0.75;
-                    for i in 0..count {
-                        let t = if count == 1 {
-                            0.0
-                        } else {
-                            (i as f32 / (count - 1) as f32) * 2.0 - 1.0
-                        };
-                        let angle = base + t * spread * 0.5;
-                        let v = Vec2::new(angle.cos(), angle.sin()) * (config.bullet_speed * 0.6);
-                        super::bullet::spawn_enemy_bullet(
-                            &mut commands,
-                            bullet_pos,
-                            v,
-                            super::bullet::EnemyBulletStyle::Shard,
-                        );
-                    }
+                    let fan = BulletPattern::Fan {
+                        angle: DOWN,
+                        count: 5,
+                        spread: 0.75,
+                        speed: config.bullet_speed * 0.6,
+                    };
+                    fire_pattern(
+                        &mut commands,
+                        &fan,
+                        BulletSource::Enemy(EnemyBulletStyle::Shard),
+                        bullet_pos,
+                        None,
+                        0,
+                    );
+                    // 压制弹向外飞出后弧线折回中央
                     for side in [-1.0, 1.0] {
                         let v = Vec2::new(side * 0.25, -1.0).normalize() * (config.bullet_speed * 0.5);
-                        super::bullet::spawn_enemy_bullet(
+                        let bullet = BulletSource::Enemy(EnemyBulletStyle::Ring).spawn(
                             &mut commands,
                             bullet_pos + Vec3::new(side * 14.0, 0.0, 0.0),
                             v,
-                            super::bullet::EnemyBulletStyle::Ring,
                         );
+                        commands.entity(bullet).insert(BulletCurve {
+                            angular_velocity: -side * 0.5,
+                            duration: 1.2,
+                        });
                     }
                 }
                 EnemyType::EliteGuard => {
-                    // 环形小弹（朝下半圆更密）
-                    let count = 10;
-                    for i in 0..count {
-                        let t = i as f32 / (count - 1) as f32; // 0..1
-                        let angle = std::f32::consts::PI * (0.15 + 0.7 * t) + std::f32::consts::PI; // mostly downward
-                        let v = Vec2::new(angle.cos(), angle.sin()) * (config.bullet_speed * 0.42);
-                        super::bullet::spawn_enemy_bullet(
-                            &mut commands,
-                            bullet_pos,
-                            v,
-                            super::bullet::EnemyBulletStyle::Ring,
-                        );
+                    // 环形小弹（朝下半圆更密）；隔一颗减速悬停后重新瞄准玩家
+                    let fan = BulletPattern::Fan {
+                        angle: DOWN,
+                        count: 10,
+                        spread: std::f32::consts::PI * 0.7,
+                        speed: config.bullet_speed * 0.42,
+                    };
+                    let bullets = fire_pattern(
+                        &mut commands,
+                        &fan,
+                        BulletSource::Enemy(EnemyBulletStyle::Ring),
+                        bullet_pos,
+                        None,
+                        0,
+                    );
+                    for bullet in bullets.into_iter().skip(1).step_by(2) {
+                        commands.entity(bullet).insert((
+                            BulletAcceleration {
+                                acceleration: config.bullet_speed * 0.5,
+                                target_speed: config.bullet_speed * 0.08,
+                            },
+                            BulletReaim {
+                                delay: 1.0,
+                                speed: config.bullet_speed * 0.55,
+                            },
+                        ));
                     }
                 }
                 _ => {
                     // 普通敌人：单发为主，类型决定样式
                     let style = if enemy.enemy_type == EnemyType::Hexagon {
-                        super::bullet::EnemyBulletStyle::Ring
+                        EnemyBulletStyle::Ring
                     } else if enemy.enemy_type == EnemyType::Small {
-                        super::bullet::EnemyBulletStyle::Needle
+                        EnemyBulletStyle::Needle
                     } else if rng.random_bool(0.25) {
-                        super::bullet::EnemyBulletStyle::Needle
+                        EnemyBulletStyle::Needle
                     } else {
-                        super::bullet::EnemyBulletStyle::Shard
-                    };
-                    super::bullet::spawn_enemy_bullet(
-                        &mut commands,
-                        bullet_pos,
-                        Vec2::new(0.0, -config.bullet_speed * 0.6),
-                        style,
-                    );
-                }
-            }
-        }
-    }
-}
-
-/// 处理敌人碰撞
-fn enemy_collision_handler(
-    mut commands: Commands,
-    mut collision_events: MessageReader<CollisionEvent>,
-    mut game_data: ResMut<GameData>,
-    boss_state: Res<BossState>,
-    mut enemy_set: ParamSet<(Query<(Entity, &Transform), With<Enemy>>, Query<&mut Enemy>)>,
-    enemy_marker: Query<(), With<Enemy>>,
-    bullets: Query<&Bullet>,
-    weapon_bullets: Query<&WeaponBullet>,
-    mut pierce: Query<&mut Pierce>,
-    mut hit_list: Query<&mut HitList>,
-    rockets: Query<&RocketBullet>,
-    transforms: Query<&Transform>,
-) {
-    for event in collision_events.read() {
-        // 检查是否涉及敌人
-        let enemy_entity = if event.layer_a == CollisionLayer::Enemy {
-            Some(event.entity_a)
-        } else if event.layer_b == CollisionLayer::Enemy {
-            Some(event.entity_b)
-        } else {
-            None
-        };
-
-        let Some(enemy_entity) = enemy_entity else {
-            continue;
-        };
-
-        // Boss 也使用 CollisionLayer::Enemy，但这里仅处理普通敌人（避免提前把子弹 despawn 导致 Boss 不掉血）
-        if enemy_marker.get(enemy_entity).is_err() {
-            continue;
-        }
-
-        // 确定另一个实体的类型
-        let other_layer = if event.layer_a == CollisionLayer::Enemy {
-            event.layer_b
-        } else {
-            event.layer_a
-        };
-
-        let other_entity = if event.layer_a == CollisionLayer::Enemy {
-            event.entity_b
-        } else {
-            event.entity_a
-        };
-
-        match other_layer {
-            CollisionLayer::PlayerBullet => {
-                // 确定伤害与子弹类型
-                if let Ok(bullet) = bullets.get(other_entity) {
-                    // 命中火花
-                    let spark_pos = transforms
-                        .get(other_entity)
-                        .map(|t| t.translation)
-                        .unwrap_or_else(|_| transforms.get(enemy_entity).map(|t| t.translation).unwrap_or_default());
-                    crate::entities::spawn_hit_sparks(&mut commands, spark_pos);
-
-                    apply_direct_damage(
-                        &mut commands,
-                        &mut game_data,
-                        boss_state.active,
-                        &mut enemy_set.p1(),
-                        enemy_entity,
-                        bullet.damage,
-                        &transforms,
-                    );
-                    commands.entity(other_entity).despawn();
-                    continue;
-                }
-
-                let Ok(weapon_bullet) = weapon_bullets.get(other_entity) else {
-                    continue;
-                };
-
-                // 避免穿透/持续类武器在连续帧对同一敌人反复结算
-                if let Ok(mut hits) = hit_list.get_mut(other_entity) {
-                    if hits.entities.contains(&enemy_entity) {
-                        continue;
-                    }
-                    hits.entities.push(enemy_entity);
-                }
-
-                // 导弹：命中立刻爆炸（AOE），不走单体伤害
-                if weapon_bullet.weapon_type == WeaponType::Rocket {
-                    let Ok(rocket) = rockets.get(other_entity) else {
-                        continue;
-                    };
-                    let Ok(rocket_tf) = transforms.get(other_entity) else {
-                        continue;
+                        EnemyBulletStyle::Shard
                     };
-
-                    let targets: Vec<Entity> = {
-                        let center2 = rocket_tf.translation.truncate();
-                        let r2 = rocket.explosion_radius * rocket.explosion_radius;
-                        enemy_set
-                            .p0()
-                            .iter()
-                            .filter_map(|(e, t)| {
-                                (t.translation.truncate().distance_squared(center2) <= r2)
-                                    .then_some(e)
-                            })
-                            .collect()
+                    let shot = BulletPattern::Fan {
+                        angle: DOWN,
+                        count: 1,
+                        spread: 0.0,
+                        speed: config.bullet_speed * 0.6,
                     };
-                    for hit_enemy in targets {
-                        apply_direct_damage(
-                            &mut commands,
-                            &mut game_data,
-                            boss_state.active,
-                            &mut enemy_set.p1(),
-                            hit_enemy,
-                            weapon_bullet.damage,
-                            &transforms,
-                        );
-                    }
-                    let shard_count = ((rocket.explosion_radius / 4.0) as u32).clamp(10, 28);
-                    spawn_rocket_explosion_particles(
+                    fire_pattern(
                         &mut commands,
-                        rocket_tf.translation,
-                        shard_count,
-                        rocket.speed,
+                        &shot,
+                        BulletSource::Enemy(style),
+                        bullet_pos,
+                        None,
+                        0,
                     );
-                    commands.entity(other_entity).despawn();
-                    continue;
-                }
-
-                // 其它武器：结算单体伤害
-                // 命中火花：长条/能量波这类“面积武器”用敌人位置更符合观感
-                let enemy_pos = transforms
-                    .get(enemy_entity)
-                    .map(|t| t.translation)
-                    .unwrap_or_default();
-                let bullet_pos = transforms
-                    .get(other_entity)
-                    .map(|t| t.translation)
-                    .unwrap_or(enemy_pos);
-                let spark_pos = match weapon_bullet.weapon_type {
-                    WeaponType::Laser | WeaponType::Beam => enemy_pos,
-                    _ => bullet_pos,
-                };
-                crate::entities::spawn_hit_sparks(&mut commands, spark_pos);
-                apply_direct_damage(
-                    &mut commands,
-                    &mut game_data,
-                    boss_state.active,
-                    &mut enemy_set.p1(),
-                    enemy_entity,
-                    weapon_bullet.damage,
-                    &transforms,
-                );
-
-                // 是否需要销毁子弹（穿透则保留）
-                let mut should_despawn = true;
-                if let Ok(mut p) = pierce.get_mut(other_entity) {
-                    if p.remaining == u32::MAX {
-                        should_despawn = false;
-                    } else if p.remaining > 1 {
-                        p.remaining -= 1;
-                        should_despawn = false;
-                    } else {
-                        p.remaining = 0;
-                        should_despawn = true;
-                    }
-                }
-
-                if should_despawn {
-                    commands.entity(other_entity).despawn();
                 }
             }
-            _ => {}
-        }
-    }
-}
-
-fn apply_direct_damage(
-    commands: &mut Commands,
-    game_data: &mut ResMut<GameData>,
-    boss_active: bool,
-    enemies: &mut Query<&mut Enemy>,
-    enemy_entity: Entity,
-    damage: i32,
-    transforms: &Query<&Transform>,
-) {
-    let Ok(mut enemy) = enemies.get_mut(enemy_entity) else {
-        return;
-    };
-
-    enemy.health -= damage;
-    if enemy.health <= 0 {
-        let score = enemy.score_value;
-        let position = transforms.get(enemy_entity).map(|t| t.translation).unwrap_or_default();
-        commands.entity(enemy_entity).despawn();
-        if boss_active {
-            // Boss 战期间的小兵：不增长经验、无掉落
-            game_data.add_score_only(score);
-        } else {
-            game_data.add_score(score);
-            let mut rng = rand::rng();
-            // 掉落：心(0.5%) / 盾(1%) / 金币(2%)，最多掉 1 个
-            let roll = rng.random_range(0.0..1.0);
-            use crate::entities::shield::{spawn_power_up, PowerUpType};
-            if roll < 0.005 {
-                spawn_power_up(commands, position, PowerUpType::ExtraLife);
-            } else if roll < 0.015 {
-                spawn_power_up(commands, position, PowerUpType::Shield);
-            } else if roll < 0.035 {
-                spawn_power_up(commands, position, PowerUpType::Coin);
-            }
         }
     }
 }
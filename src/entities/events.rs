@@ -0,0 +1,132 @@
+//! 玩法事件总线
+//! 由现有玩法系统发出的类型化消息，供成就、音效、统计、UI 等插件订阅，
+//! 订阅方只需 `MessageReader<EnemyKilled>` 等读取，无需改动产生方
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use super::boss::BossType;
+use super::boss_parts::BossPartKind;
+use super::enemy::EnemyType;
+use super::shield::PowerUpType;
+use super::weapons::WeaponType;
+
+/// 玩法事件插件
+pub struct GameplayEventsPlugin;
+
+impl Plugin for GameplayEventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<EnemyKilled>()
+            .add_message::<BossSpawned>()
+            .add_message::<BossDefeated>()
+            .add_message::<BossPhaseChanged>()
+            .add_message::<BossPartDestroyed>()
+            .add_message::<PlayerDamaged>()
+            .add_message::<PowerUpCollected>()
+            .add_message::<LevelUp>()
+            .add_message::<WeaponAcquired>();
+    }
+}
+
+/// 普通敌人被击毁
+#[derive(Message, Debug, Clone)]
+pub struct EnemyKilled {
+    pub entity: Entity,
+    pub enemy_type: EnemyType,
+    pub position: Vec3,
+    /// 基础分值（未计连击倍率）
+    pub score: u32,
+    /// 致命一击的武器（基础子弹为 None）
+    pub weapon: Option<WeaponType>,
+}
+
+/// Boss 出场（多 Boss 战时每个 Boss 各发一次）
+#[derive(Message, Debug, Clone)]
+pub struct BossSpawned {
+    pub entity: Entity,
+    pub boss_type: BossType,
+    /// 是否为中型 Boss
+    pub mid_boss: bool,
+}
+
+/// Boss 被击败（该 Boss 的所有实体都被击毁）
+#[derive(Message, Debug, Clone)]
+pub struct BossDefeated {
+    pub entity: Entity,
+    pub boss_type: BossType,
+    pub position: Vec3,
+    pub score: u32,
+    pub mid_boss: bool,
+    /// 是否为本场战斗最后一个被击败的 Boss
+    pub final_boss: bool,
+}
+
+/// Boss 切换阶段（按血量）
+#[derive(Message, Debug, Clone)]
+pub struct BossPhaseChanged {
+    pub entity: Entity,
+    pub boss_type: BossType,
+    pub previous_phase: u32,
+    pub phase: u32,
+}
+
+/// Boss 部件（炮塔、装甲板）被击毁
+#[derive(Message, Debug, Clone)]
+pub struct BossPartDestroyed {
+    pub boss: Entity,
+    pub part: Entity,
+    pub kind: BossPartKind,
+    /// 在 ActiveBoss::parts 中的序号
+    pub slot: usize,
+    pub position: Vec3,
+}
+
+/// 玩家受到攻击（护盾吸收也算）
+#[derive(Message, Debug, Clone)]
+pub struct PlayerDamaged {
+    pub player: Entity,
+    /// 造成伤害的敌人或敌弹
+    pub source: Entity,
+    /// 是否被护盾吸收
+    pub shield_absorbed: bool,
+    /// 受击后剩余生命
+    pub lives_left: u32,
+}
+
+/// 玩家拾取道具
+#[derive(Message, Debug, Clone)]
+pub struct PowerUpCollected {
+    pub player: Entity,
+    pub power_type: PowerUpType,
+    pub position: Vec3,
+}
+
+/// 战机升级（由升级界面的 check_level_up 发出，一次跳多级时每级各发一次）
+#[derive(Message, Debug, Clone)]
+pub struct LevelUp {
+    pub level: u32,
+}
+
+/// 通过升级获得新武器或武器升级
+#[derive(Message, Debug, Clone)]
+pub struct WeaponAcquired {
+    pub weapon_type: WeaponType,
+    /// 获得/升级后的等级
+    pub level: u32,
+    /// 是否为新获得的武器
+    pub is_new: bool,
+}
+
+/// 发出玩法事件的系统参数：把所有事件写入器打包为一个参数
+#[derive(SystemParam)]
+pub struct GameplayEvents<'w> {
+    pub enemy_killed: MessageWriter<'w, EnemyKilled>,
+    pub boss_spawned: MessageWriter<'w, BossSpawned>,
+    pub boss_defeated: MessageWriter<'w, BossDefeated>,
+    pub boss_phase_changed: MessageWriter<'w, BossPhaseChanged>,
+    pub boss_part_destroyed: MessageWriter<'w, BossPartDestroyed>,
+    pub player_damaged: MessageWriter<'w, PlayerDamaged>,
+    pub power_up_collected: MessageWriter<'w, PowerUpCollected>,
+    pub level_up: MessageWriter<'w, LevelUp>,
+    pub weapon_acquired: MessageWriter<'w, WeaponAcquired>,
+}
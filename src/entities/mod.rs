@@ -2,15 +2,35 @@
 //! 包含玩家、敌人、子弹、护盾、武器、Boss等游戏实体
 
 mod boss;
+mod boss_mechanics;
+mod boss_movement;
+mod boss_parts;
 mod bullet;
+mod bullet_behavior;
+mod bullet_pattern;
+mod damage;
 mod enemy;
+mod events;
+mod passives;
 mod player;
 mod shield;
+mod special_weapons;
+mod telegraph;
 pub mod weapons;
 
 pub use boss::*;
+pub use boss_mechanics::*;
+pub use boss_movement::*;
+pub use boss_parts::*;
 pub use bullet::*;
+pub use bullet_behavior::*;
+pub use bullet_pattern::*;
+pub use damage::*;
 pub use enemy::*;
+pub use events::*;
+pub use passives::*;
 pub use player::*;
 pub use shield::*;
+pub use special_weapons::*;
+pub use telegraph::*;
 pub use weapons::*;
@@ -0,0 +1,347 @@
+//! 被动道具
+//! 伤害、射速、移速、拾取范围、弹速、经验、幸运等被动强化；
+//! 各道具等级汇总为战机上的 `StatModifiers`，武器生成函数与相关系统统一读取
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::game::{not_upgrading, GameConfig, GameData, GameState};
+
+use super::{Player, PowerUp};
+
+/// 被动道具插件
+pub struct PassivePlugin;
+
+impl Plugin for PassivePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (sync_stat_modifiers, attract_power_ups.run_if(not_upgrading))
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// 被动道具等级上限
+pub const MAX_PASSIVE_LEVEL: u32 = 5;
+/// 被动道具槽位数
+pub const MAX_PASSIVE_SLOTS: usize = 5;
+/// 道具被吸向战机的速度
+const PICKUP_PULL_SPEED: f32 = 420.0;
+
+/// 被动道具类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PassiveType {
+    /// 伤害提升
+    Damage,
+    /// 射速提升（缩短武器冷却）
+    FireRate,
+    /// 移动速度提升
+    MoveSpeed,
+    /// 道具吸附范围
+    PickupRadius,
+    /// 弹速提升
+    ProjectileSpeed,
+    /// 经验获取提升
+    Experience,
+    /// 掉落概率提升
+    Luck,
+}
+
+impl PassiveType {
+    /// 获取道具名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            PassiveType::Damage => "火力核心",
+            PassiveType::FireRate => "速射装置",
+            PassiveType::MoveSpeed => "推进器",
+            PassiveType::PickupRadius => "磁力线圈",
+            PassiveType::ProjectileSpeed => "加速膛线",
+            PassiveType::Experience => "学习芯片",
+            PassiveType::Luck => "幸运星",
+        }
+    }
+
+    /// 所有被动道具
+    pub fn all() -> &'static [PassiveType] {
+        &[
+            PassiveType::Damage,
+            PassiveType::FireRate,
+            PassiveType::MoveSpeed,
+            PassiveType::PickupRadius,
+            PassiveType::ProjectileSpeed,
+            PassiveType::Experience,
+            PassiveType::Luck,
+        ]
+    }
+
+    /// 每级加成（吸附范围为像素，其余为倍率增量）
+    pub fn bonus_per_level(&self) -> f32 {
+        match self {
+            PassiveType::Damage => 0.10,
+            PassiveType::FireRate => 0.08,
+            PassiveType::MoveSpeed => 0.08,
+            PassiveType::PickupRadius => 40.0,
+            PassiveType::ProjectileSpeed => 0.10,
+            PassiveType::Experience => 0.10,
+            PassiveType::Luck => 0.25,
+        }
+    }
+}
+
+/// 单个被动道具
+#[derive(Debug, Clone)]
+pub struct PassiveItem {
+    pub passive_type: PassiveType,
+    pub level: u32,
+}
+
+/// 战机持有的被动道具
+#[derive(Component, Debug, Clone, Default)]
+pub struct PassiveItems {
+    pub items: Vec<PassiveItem>,
+}
+
+impl PassiveItems {
+    /// 道具等级（未持有为 0）
+    pub fn level(&self, passive_type: PassiveType) -> u32 {
+        self.items
+            .iter()
+            .find(|item| item.passive_type == passive_type)
+            .map_or(0, |item| item.level)
+    }
+
+    /// 槽位是否已满
+    pub fn is_full(&self) -> bool {
+        self.items.len() >= MAX_PASSIVE_SLOTS
+    }
+
+    /// 添加或升级道具
+    pub fn add_or_upgrade(&mut self, passive_type: PassiveType) {
+        if let Some(item) = self
+            .items
+            .iter_mut()
+            .find(|item| item.passive_type == passive_type)
+        {
+            item.level = (item.level + 1).min(MAX_PASSIVE_LEVEL);
+        } else if !self.is_full() {
+            self.items.push(PassiveItem {
+                passive_type,
+                level: 1,
+            });
+        }
+    }
+
+    /// 汇总全部道具的属性修正
+    pub fn modifiers(&self) -> StatModifiers {
+        let bonus = |passive_type: PassiveType| {
+            self.level(passive_type) as f32 * passive_type.bonus_per_level()
+        };
+        StatModifiers {
+            damage: 1.0 + bonus(PassiveType::Damage),
+            fire_rate: 1.0 + bonus(PassiveType::FireRate),
+            move_speed: 1.0 + bonus(PassiveType::MoveSpeed),
+            pickup_radius: bonus(PassiveType::PickupRadius),
+            projectile_speed: 1.0 + bonus(PassiveType::ProjectileSpeed),
+            experience: 1.0 + bonus(PassiveType::Experience),
+            luck: 1.0 + bonus(PassiveType::Luck),
+        }
+    }
+}
+
+/// 属性修正（由被动道具汇总，挂在战机上）
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct StatModifiers {
+    /// 伤害倍率
+    pub damage: f32,
+    /// 射速倍率（武器冷却除以该值）
+    pub fire_rate: f32,
+    /// 移动速度倍率
+    pub move_speed: f32,
+    /// 道具吸附半径（0 为不吸附）
+    pub pickup_radius: f32,
+    /// 弹速倍率
+    pub projectile_speed: f32,
+    /// 经验获取倍率
+    pub experience: f32,
+    /// 掉落概率倍率
+    pub luck: f32,
+}
+
+impl Default for StatModifiers {
+    fn default() -> Self {
+        Self {
+            damage: 1.0,
+            fire_rate: 1.0,
+            move_speed: 1.0,
+            pickup_radius: 0.0,
+            projectile_speed: 1.0,
+            experience: 1.0,
+            luck: 1.0,
+        }
+    }
+}
+
+impl StatModifiers {
+    /// 按伤害倍率放大伤害；小数部分按概率进位，低伤害武器也能按期望受益
+    pub fn scale_damage(&self, base: i32) -> i32 {
+        let scaled = base as f32 * self.damage;
+        let whole = scaled.floor();
+        let round_up = rand::rng().random_bool((scaled - whole).clamp(0.0, 1.0) as f64);
+        whole as i32 + round_up as i32
+    }
+
+    /// 按弹速倍率放大速度
+    pub fn scale_speed(&self, speed: f32) -> f32 {
+        speed * self.projectile_speed
+    }
+
+    /// 按射速倍率缩短冷却
+    pub fn scale_cooldown(&self, cooldown: f32) -> f32 {
+        cooldown / self.fire_rate
+    }
+}
+
+/// 被动道具变化时重新汇总属性修正，并同步移速与经验倍率
+fn sync_stat_modifiers(
+    config: Res<GameConfig>,
+    mut game_data: ResMut<GameData>,
+    mut players: Query<(&PassiveItems, &mut StatModifiers, &mut Player), Changed<PassiveItems>>,
+) {
+    let Ok((items, mut stats, mut player)) = players.single_mut() else {
+        return;
+    };
+    *stats = items.modifiers();
+    player.speed = config.player_speed * stats.move_speed;
+    game_data.exp_multiplier = stats.experience;
+}
+
+/// 吸附范围内的道具飞向战机
+fn attract_power_ups(
+    time: Res<Time>,
+    player_query: Query<(&Transform, &StatModifiers), With<Player>>,
+    mut power_ups: Query<&mut Transform, (With<PowerUp>, Without<Player>)>,
+) {
+    let Ok((player_tf, stats)) = player_query.single() else {
+        return;
+    };
+    if stats.pickup_radius <= 0.0 {
+        return;
+    }
+
+    let target = player_tf.translation.truncate();
+    for mut transform in power_ups.iter_mut() {
+        let offset = target - transform.translation.truncate();
+        let distance = offset.length();
+        if distance > stats.pickup_radius || distance < 1.0 {
+            continue;
+        }
+        let step = (PICKUP_PULL_SPEED * time.delta_secs()).min(distance);
+        transform.translation += (offset / distance * step).extend(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items_with(passive_type: PassiveType, upgrades: u32) -> PassiveItems {
+        let mut items = PassiveItems::default();
+        for _ in 0..upgrades {
+            items.add_or_upgrade(passive_type);
+        }
+        items
+    }
+
+    /// 某个道具对应的属性（吸附范围为像素，其余为倍率）
+    fn stat(stats: &StatModifiers, passive_type: PassiveType) -> f32 {
+        match passive_type {
+            PassiveType::Damage => stats.damage,
+            PassiveType::FireRate => stats.fire_rate,
+            PassiveType::MoveSpeed => stats.move_speed,
+            PassiveType::PickupRadius => stats.pickup_radius,
+            PassiveType::ProjectileSpeed => stats.projectile_speed,
+            PassiveType::Experience => stats.experience,
+            PassiveType::Luck => stats.luck,
+        }
+    }
+
+    #[test]
+    fn no_passives_gives_default_modifiers() {
+        assert_eq!(
+            PassiveItems::default().modifiers(),
+            StatModifiers::default()
+        );
+    }
+
+    #[test]
+    fn levels_stack_per_passive() {
+        let mut items = items_with(PassiveType::Damage, 3);
+        items.add_or_upgrade(PassiveType::FireRate);
+        let stats = items.modifiers();
+
+        assert_eq!(items.level(PassiveType::Damage), 3);
+        assert!((stats.damage - 1.3).abs() < 1e-5);
+        assert!((stats.fire_rate - 1.08).abs() < 1e-5);
+        // 未持有的道具不影响属性
+        assert_eq!(stats.move_speed, 1.0);
+        assert_eq!(stats.pickup_radius, 0.0);
+    }
+
+    #[test]
+    fn every_passive_caps_at_max_level() {
+        let base = StatModifiers::default();
+        for &passive_type in PassiveType::all() {
+            let items = items_with(passive_type, MAX_PASSIVE_LEVEL + 3);
+            assert_eq!(items.level(passive_type), MAX_PASSIVE_LEVEL);
+
+            let expected = stat(&base, passive_type)
+                + MAX_PASSIVE_LEVEL as f32 * passive_type.bonus_per_level();
+            let actual = stat(&items.modifiers(), passive_type);
+            assert!(
+                (actual - expected).abs() < 1e-4,
+                "{passive_type:?}: {actual} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn new_passives_are_ignored_when_slots_are_full() {
+        let mut items = PassiveItems::default();
+        for &passive_type in PassiveType::all() {
+            items.add_or_upgrade(passive_type);
+        }
+        assert_eq!(items.items.len(), MAX_PASSIVE_SLOTS);
+        let skipped = PassiveType::all()[MAX_PASSIVE_SLOTS];
+        assert_eq!(items.level(skipped), 0);
+        assert_eq!(
+            stat(&items.modifiers(), skipped),
+            stat(&StatModifiers::default(), skipped)
+        );
+    }
+
+    #[test]
+    fn scale_damage_without_fraction_is_exact() {
+        let stats = items_with(PassiveType::Damage, MAX_PASSIVE_LEVEL).modifiers();
+        for _ in 0..100 {
+            assert_eq!(stats.scale_damage(10), 15);
+        }
+        assert_eq!(StatModifiers::default().scale_damage(7), 7);
+    }
+
+    #[test]
+    fn scale_damage_rounds_fraction_up_by_chance() {
+        // 1 × 1.3 = 1.3：每次为 1 或 2，平均约 1.3
+        let stats = items_with(PassiveType::Damage, 3).modifiers();
+        let samples = 4000;
+        let total: i32 = (0..samples)
+            .map(|_| {
+                let damage = stats.scale_damage(1);
+                assert!(damage == 1 || damage == 2, "damage = {damage}");
+                damage
+            })
+            .sum();
+        let mean = total as f32 / samples as f32;
+        assert!((mean - 1.3).abs() < 0.05, "mean = {mean}");
+    }
+}
@@ -6,13 +6,17 @@ use rand::Rng;
 
 use crate::game::{
     not_upgrading, Collider, CollisionEvent, CollisionLayer, CollisionMask, GameConfig, GameData,
-    GameState,
+    GameState, GrazeEvent,
 };
 use crate::geometry::{spawn_geometry_entity, GeometryBlueprint};
+use crate::storage::SaveData;
 
 use super::bullet::ShootCooldown;
 use super::weapons::*;
-use super::{Boss, BossState, Enemy};
+use super::{
+    Boss, DamageEvent, Enemy, GameplayEvents, Health, PassiveItems, PlayerDamaged,
+    PowerUpCollected, StatModifiers,
+};
 
 /// 玩家插件
 pub struct PlayerPlugin;
@@ -21,7 +25,8 @@ impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(DragState::default())
             .insert_resource(AutoShootTimer::default())
-            .add_systems(OnEnter(GameState::Playing), spawn_player)
+            .insert_resource(FireControl::default())
+            .add_systems(OnEnter(GameState::Playing), (spawn_player, sync_fire_mode))
             .add_systems(OnEnter(GameState::Menu), despawn_player)
             .add_systems(OnEnter(GameState::GameOver), despawn_player)
             .add_systems(OnEnter(GameState::Recharge), despawn_player)
@@ -42,14 +47,19 @@ impl Plugin for PlayerPlugin {
                 (
                     player_touch_movement,
                     player_keyboard_movement,
+                    player_dash,
+                    update_fire_control.before(auto_shoot_weapons),
+                    update_charge_glow,
                     auto_shoot_weapons,
                     update_weapon_bullets,
                     update_rocket_bullets,
                     update_aura_orbs,
+                    (update_laser_beam, apply_laser_damage).chain(),
                     update_homing_missiles,
                     resolve_lightning_casts,
                     handle_player_bullet_vs_enemy_bullet,
                     player_collision_handler,
+                    handle_grazes,
                     update_invincibility,
                 )
                     .run_if(in_state(GameState::Playing))
@@ -65,6 +75,12 @@ fn reset_drag_state_when_upgrading(game_data: Res<GameData>, mut drag_state: Res
     }
 }
 
+/// 每次擦弹获得的分数（同时计入经验）
+const GRAZE_SCORE: u32 = 20;
+
+/// 每个积分宝石的分数（不计经验）
+const SCORE_GEM_POINTS: u32 = 100;
+
 /// 玩家组件
 #[derive(Component)]
 pub struct Player {
@@ -83,6 +99,64 @@ impl Default for Player {
     }
 }
 
+/// 冲刺等级上限
+pub const MAX_DASH_LEVEL: u32 = 5;
+
+/// 冲刺速度（像素/秒）
+const DASH_SPEED: f32 = 1100.0;
+/// 冲刺持续时间（秒）
+const DASH_DURATION: f32 = 0.14;
+/// 冲刺提供的无敌时间（秒），略长于冲刺本身
+const DASH_INVINCIBLE_TIME: f32 = 0.35;
+/// 冲刺基础冷却（秒）
+const DASH_BASE_COOLDOWN: f32 = 1.6;
+/// 残影生成间隔（秒）
+const DASH_AFTERIMAGE_INTERVAL: f32 = 0.025;
+
+/// 冲刺组件：Shift / X 向输入方向快速位移并短暂无敌
+#[derive(Component)]
+pub struct Dash {
+    /// 冲刺等级（升级卡降低冷却），0 为初始
+    pub level: u32,
+    /// 冷却剩余时间
+    pub cooldown_timer: f32,
+    /// 冲刺剩余时间（> 0 表示正在冲刺）
+    pub active_timer: f32,
+    /// 冲刺方向（单位向量）
+    pub direction: Vec2,
+    /// 残影计时器
+    pub afterimage_timer: f32,
+}
+
+impl Default for Dash {
+    fn default() -> Self {
+        Self {
+            level: 0,
+            cooldown_timer: 0.0,
+            active_timer: 0.0,
+            direction: Vec2::Y,
+            afterimage_timer: 0.0,
+        }
+    }
+}
+
+impl Dash {
+    /// 当前冷却时间：每级 -12%
+    pub fn cooldown(&self) -> f32 {
+        DASH_BASE_COOLDOWN * (1.0 - 0.12 * self.level as f32)
+    }
+
+    pub fn is_max_level(&self) -> bool {
+        self.level >= MAX_DASH_LEVEL
+    }
+
+    pub fn level_up(&mut self) {
+        if self.level < MAX_DASH_LEVEL {
+            self.level += 1;
+        }
+    }
+}
+
 /// 拖拽状态资源
 #[derive(Resource, Default)]
 pub struct DragState {
@@ -106,6 +180,35 @@ impl Default for AutoShootTimer {
     }
 }
 
+/// 射击控制：自动/手动模式与默认子弹蓄力状态
+#[derive(Resource, Default)]
+pub struct FireControl {
+    /// 手动射击模式（来自设置中的 auto_fire = false）
+    pub manual: bool,
+    /// 本帧是否按住射击键（空格/Z 或触摸拖拽）
+    pub held: bool,
+    /// 当前蓄力时间（秒）
+    pub charge: f32,
+}
+
+impl FireControl {
+    /// 蓄力比例（0~1）
+    pub fn charge_ratio(&self) -> f32 {
+        (self.charge / MAX_CHARGE_TIME).min(1.0)
+    }
+}
+
+/// 蓄力光球（挂在战机机头，随蓄力变大）
+#[derive(Component)]
+struct ChargeGlow;
+
+/// 进入 Playing 时同步射击模式设置
+fn sync_fire_mode(save_data: Res<SaveData>, mut fire_control: ResMut<FireControl>) {
+    fire_control.manual = !save_data.settings.auto_fire;
+    fire_control.held = false;
+    fire_control.charge = 0.0;
+}
+
 /// 生成玩家
 fn spawn_player(mut commands: Commands, config: Res<GameConfig>, existing: Query<Entity, With<Player>>) {
     // 从 Paused -> Playing 恢复时，不重复生成玩家
@@ -123,13 +226,16 @@ fn spawn_player(mut commands: Commands, config: Res<GameConfig>, existing: Query
             speed: config.player_speed,
             ..default()
         },
-        Collider::new(blueprint.collision.clone(), CollisionLayer::Player)
+        Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER)
             .with_mask(CollisionMask::player_mask()),
         ShootCooldown {
             timer: 0.0,
             cooldown: config.shoot_cooldown,
         },
         WeaponInventory::new(),
+        Dash::default(),
+        PassiveItems::default(),
+        StatModifiers::default(),
     ));
 
     log::info!("Player spawned");
@@ -203,6 +309,7 @@ fn despawn_player(
     mut commands: Commands,
     query: Query<Entity, With<Player>>,
     aura_query: Query<Entity, With<AuraOrb>>,
+    laser_query: Query<Entity, With<LaserBeam>>,
     bullet_query: Query<Entity, With<WeaponBullet>>,
 ) {
     for entity in query.iter() {
@@ -212,6 +319,10 @@ fn despawn_player(
     for entity in aura_query.iter() {
         commands.entity(entity).despawn();
     }
+    // 销毁激光光束
+    for entity in laser_query.iter() {
+        commands.entity(entity).despawn();
+    }
     // 销毁所有武器子弹
     for entity in bullet_query.iter() {
         commands.entity(entity).despawn();
@@ -337,17 +448,227 @@ fn player_keyboard_movement(
     }
 }
 
+/// 冲刺：按下 Shift / X 向移动方向（无输入时向前）快速位移，期间无敌并留下残影
+fn player_dash(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<GameConfig>,
+    mut query: Query<(&mut Transform, &mut Player, &mut Dash)>,
+) {
+    let Ok((mut transform, mut player, mut dash)) = query.single_mut() else {
+        return;
+    };
+
+    let delta = time.delta_secs();
+    dash.cooldown_timer -= delta;
+
+    let pressed = keyboard.just_pressed(KeyCode::ShiftLeft)
+        || keyboard.just_pressed(KeyCode::ShiftRight)
+        || keyboard.just_pressed(KeyCode::KeyX);
+
+    if pressed && dash.active_timer <= 0.0 && dash.cooldown_timer <= 0.0 {
+        let mut direction = Vec2::ZERO;
+        if keyboard.pressed(KeyCode::ArrowLeft) || keyboard.pressed(KeyCode::KeyA) {
+            direction.x -= 1.0;
+        }
+        if keyboard.pressed(KeyCode::ArrowRight) || keyboard.pressed(KeyCode::KeyD) {
+            direction.x += 1.0;
+        }
+        if keyboard.pressed(KeyCode::ArrowUp) || keyboard.pressed(KeyCode::KeyW) {
+            direction.y += 1.0;
+        }
+        if keyboard.pressed(KeyCode::ArrowDown) || keyboard.pressed(KeyCode::KeyS) {
+            direction.y -= 1.0;
+        }
+
+        dash.direction = if direction == Vec2::ZERO {
+            Vec2::Y
+        } else {
+            direction.normalize()
+        };
+        dash.active_timer = DASH_DURATION;
+        dash.afterimage_timer = 0.0;
+        dash.cooldown_timer = dash.cooldown();
+
+        // 复用受伤后的无敌逻辑（闪烁由 update_invincibility 处理）
+        player.invincible = true;
+        player.invincible_timer = player.invincible_timer.max(DASH_INVINCIBLE_TIME);
+    }
+
+    if dash.active_timer <= 0.0 {
+        return;
+    }
+
+    dash.active_timer -= delta;
+    let step = dash.direction * DASH_SPEED * delta;
+    transform.translation.x += step.x;
+    transform.translation.y += step.y;
+
+    let half_width = config.window_width / 2.0 - 30.0;
+    let half_height = config.window_height / 2.0 - 30.0;
+    transform.translation.x = transform.translation.x.clamp(-half_width, half_width);
+    transform.translation.y = transform.translation.y.clamp(-half_height, half_height);
+
+    dash.afterimage_timer -= delta;
+    if dash.afterimage_timer <= 0.0 {
+        dash.afterimage_timer = DASH_AFTERIMAGE_INTERVAL;
+        spawn_dash_afterimage(&mut commands, transform.translation);
+    }
+}
+
+/// 冲刺残影：战机外形的半透明青色拷贝，短暂闪烁后消失
+fn spawn_dash_afterimage(commands: &mut Commands, position: Vec3) {
+    use crate::geometry::{GeometryShape, ShapeColor};
+
+    let tint = |c: ShapeColor| ShapeColor::new(c.r * 0.4, c.g * 0.7 + 0.3, 1.0, c.a * 0.25);
+    let mut blueprint = GeometryBlueprint::player_raiden_mk1();
+    blueprint.name = "dash_afterimage".to_string();
+    for shape in blueprint.shapes.iter_mut() {
+        match shape {
+            GeometryShape::Polygon { color, .. }
+            | GeometryShape::Arc { color, .. }
+            | GeometryShape::Circle { color, .. }
+            | GeometryShape::Line { color, .. } => *color = tint(*color),
+        }
+    }
+
+    let entity = spawn_geometry_entity(commands, &blueprint, position - Vec3::new(0.0, 0.0, 1.0));
+    commands.entity(entity).insert(BlinkEffect {
+        remaining: 0.18,
+        period: 0.06,
+        on_time: 0.04,
+        phase: 0.0,
+    });
+}
+
+/// 手动模式：按下射击键点射，按住蓄力，松开时蓄力足够则释放穿透蓄力弹
+fn update_fire_control(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    drag_state: Res<DragState>,
+    config: Res<GameConfig>,
+    mut fire_control: ResMut<FireControl>,
+    query: Query<(&Transform, &WeaponInventory, &StatModifiers), With<Player>>,
+) {
+    if !fire_control.manual {
+        return;
+    }
+
+    let was_held = fire_control.held;
+    fire_control.held =
+        keyboard.pressed(KeyCode::Space) || keyboard.pressed(KeyCode::KeyZ) || drag_state.dragging;
+
+    let Ok((transform, inventory, stats)) = query.single() else {
+        fire_control.charge = 0.0;
+        return;
+    };
+    let uses_default_bullet = inventory.weapons.is_empty() || inventory.has_default_bullet;
+    let bullet_pos = transform.translation + Vec3::new(0.0, 25.0, 0.0);
+
+    if fire_control.held {
+        if !was_held && uses_default_bullet {
+            let speed = config.bullet_speed;
+            super::bullet::spawn_player_bullet(&mut commands, bullet_pos, speed, stats);
+        }
+        fire_control.charge += time.delta_secs();
+    } else {
+        let ratio = fire_control.charge_ratio();
+        if was_held && uses_default_bullet && ratio >= MIN_CHARGE_RATIO {
+            spawn_charged_shot(&mut commands, bullet_pos, ratio, config.bullet_speed, stats);
+        }
+        fire_control.charge = 0.0;
+    }
+}
+
+/// 蓄力光球显示：蓄力超过阈值后在机头显示，随蓄力放大，蓄满后闪烁
+fn update_charge_glow(
+    mut commands: Commands,
+    time: Res<Time>,
+    fire_control: Res<FireControl>,
+    player_query: Query<(Entity, &WeaponInventory), With<Player>>,
+    mut glow_query: Query<(Entity, &mut Transform, &mut Visibility), With<ChargeGlow>>,
+) {
+    let ratio = fire_control.charge_ratio();
+    let show = fire_control.manual
+        && fire_control.held
+        && ratio >= MIN_CHARGE_RATIO
+        && player_query
+            .iter()
+            .any(|(_, inv)| inv.weapons.is_empty() || inv.has_default_bullet);
+
+    if !show {
+        for (entity, _, _) in glow_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    if let Ok((_, mut transform, mut visibility)) = glow_query.single_mut() {
+        transform.scale = Vec3::splat(0.4 + 0.6 * ratio);
+        // 蓄满后快速闪烁提示可以松开
+        *visibility = if ratio >= 1.0 && (time.elapsed_secs() * 16.0).sin() < 0.0 {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        return;
+    }
+
+    let Ok((player, _)) = player_query.single() else {
+        return;
+    };
+    use crate::geometry::{CollisionShape, GeometryShape, ShapeColor, Vec2D};
+    let blueprint = GeometryBlueprint {
+        name: "charge_glow".to_string(),
+        shapes: vec![
+            GeometryShape::Circle {
+                center: Vec2D::ZERO,
+                radius: 16.0,
+                color: ShapeColor::new(0.3, 0.85, 1.0, 0.25),
+                fill: true,
+                stroke_width: 1.0,
+            },
+            GeometryShape::Circle {
+                center: Vec2D::ZERO,
+                radius: 7.0,
+                color: ShapeColor::new(0.9, 1.0, 1.0, 0.9),
+                fill: true,
+                stroke_width: 1.0,
+            },
+        ],
+        collision: CollisionShape::Circle { radius: 0.0 },
+        scale: 1.0,
+    };
+    let glow = spawn_geometry_entity(&mut commands, &blueprint, Vec3::ZERO);
+    commands.entity(glow).insert((
+        ChargeGlow,
+        Transform::from_translation(Vec3::new(0.0, 28.0, 40.0)).with_scale(Vec3::splat(0.4)),
+    ));
+    commands.entity(player).add_child(glow);
+}
+
 /// 自动发射武器
 fn auto_shoot_weapons(
     mut commands: Commands,
     time: Res<Time>,
     config: Res<GameConfig>,
+    fire_control: Res<FireControl>,
     mut auto_timer: ResMut<AutoShootTimer>,
-    mut query: Query<(&Transform, &mut WeaponInventory, &mut ShootCooldown), With<Player>>,
+    mut query: Query<
+        (
+            &Transform,
+            &mut WeaponInventory,
+            &mut ShootCooldown,
+            &StatModifiers,
+        ),
+        With<Player>,
+    >,
     // 明确排除 Player，避免与玩家 Query 在 Transform 访问上产生潜在重叠（B0001）
     enemy_query: Query<(Entity, &Transform), (Or<(With<Enemy>, With<Boss>)>, Without<Player>)>,
 ) {
-    let Ok((transform, mut inventory, mut cooldown)) = query.single_mut() else {
+    let Ok((transform, mut inventory, mut cooldown, stats)) = query.single_mut() else {
         return;
     };
 
@@ -362,12 +683,18 @@ fn auto_shoot_weapons(
 
     let player_pos = transform.translation;
 
+    // 手动模式下默认子弹由 update_fire_control 处理（点射/蓄力），其余武器仅在按住时发射
+    if fire_control.manual && !fire_control.held {
+        return;
+    }
+
     // 如果没有武器，使用默认子弹
     if inventory.weapons.is_empty() || inventory.has_default_bullet {
-        if cooldown.timer <= 0.0 {
+        if !fire_control.manual && cooldown.timer <= 0.0 {
             let bullet_pos = player_pos + Vec3::new(0.0, 25.0, 0.0);
-            super::bullet::spawn_player_bullet(&mut commands, bullet_pos, config.bullet_speed);
-            cooldown.timer = cooldown.cooldown;
+            let speed = config.bullet_speed;
+            super::bullet::spawn_player_bullet(&mut commands, bullet_pos, speed, stats);
+            cooldown.timer = stats.scale_cooldown(cooldown.cooldown);
         }
         if inventory.weapons.is_empty() {
             return;
@@ -405,6 +732,7 @@ fn auto_shoot_weapons(
                         player_pos,
                         weapon.level,
                         config.bullet_speed,
+                        stats,
                     );
                 }
                 WeaponType::Rocket => {
@@ -414,10 +742,11 @@ fn auto_shoot_weapons(
                         weapon.level,
                         nearest_enemy,
                         config.bullet_speed,
+                        stats,
                     );
                 }
-                WeaponType::Laser => {
-                    spawn_laser(&mut commands, player_pos, weapon.level);
+                WeaponType::Laser | WeaponType::ArcLaser => {
+                    // 激光为持续光束，在 update_laser_beam 中处理
                 }
                 WeaponType::Homing => {
                     spawn_homing_missile(
@@ -426,22 +755,59 @@ fn auto_shoot_weapons(
                         weapon.level,
                         nearest_enemy,
                         config.bullet_speed,
+                        stats,
                     );
                 }
                 WeaponType::Lightning => {
                     // 生成一次性“施法请求”，由 resolve_lightning_casts 解析并结算伤害
                     if nearest_enemy.is_some() {
-                        spawn_lightning(&mut commands, player_pos, weapon.level, nearest_enemy);
+                        spawn_lightning(
+                            &mut commands,
+                            player_pos,
+                            weapon.level,
+                            nearest_enemy,
+                            stats,
+                        );
                     }
                 }
                 WeaponType::Aura => {
                     // Aura不需要发射，在 update_aura_orbs 中处理
                 }
                 WeaponType::Beam => {
-                    spawn_beam_wave(&mut commands, &config, player_pos, weapon.level);
+                    spawn_beam_wave(&mut commands, &config, player_pos, weapon.level, stats);
+                }
+                WeaponType::Boomerang => {
+                    spawn_boomerang_blades(
+                        &mut commands,
+                        player_pos,
+                        weapon.level,
+                        config.bullet_speed,
+                        stats,
+                    );
+                }
+                WeaponType::Mine => {
+                    spawn_proximity_mines(&mut commands, player_pos, weapon.level, stats);
+                }
+                WeaponType::Flamethrower => {
+                    spawn_flame_burst(&mut commands, player_pos, weapon.level, stats);
+                }
+                WeaponType::GravityWell => {
+                    spawn_gravity_well(&mut commands, player_pos, weapon.level, stats);
+                }
+                WeaponType::SwarmMissiles => {
+                    spawn_swarm_missiles(
+                        &mut commands,
+                        player_pos,
+                        nearest_enemy,
+                        config.bullet_speed,
+                        stats,
+                    );
+                }
+                WeaponType::InfernoVortex => {
+                    spawn_inferno_vortex(&mut commands, player_pos, stats);
                 }
             }
-            weapon.timer = weapon.cooldown;
+            weapon.timer = stats.scale_cooldown(weapon.cooldown);
         }
     }
 }
@@ -452,13 +818,21 @@ fn update_weapon_bullets(
     time: Res<Time>,
     config: Res<GameConfig>,
     // RocketBullet 在 update_rocket_bullets 内处理（需要爆炸结算）
-    mut query: Query<(Entity, &mut Transform, &mut WeaponBullet), Without<RocketBullet>>,
+    mut query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut WeaponBullet,
+            Has<BoomerangBlade>,
+        ),
+        Without<RocketBullet>,
+    >,
 ) {
     let delta = time.delta_secs();
     let half_height = config.window_height / 2.0 + 50.0;
     let half_width = config.window_width / 2.0 + 50.0;
 
-    for (entity, mut transform, mut bullet) in query.iter_mut() {
+    for (entity, mut transform, mut bullet, boomerang) in query.iter_mut() {
         // 更新生命周期
         bullet.lifetime -= delta;
         if bullet.lifetime <= 0.0 {
@@ -471,7 +845,10 @@ fn update_weapon_bullets(
         transform.translation.x += velocity.x;
         transform.translation.y += velocity.y;
 
-        // 边界检查
+        // 边界检查（回旋刃飞出屏幕后还会折返，只受生命周期限制）
+        if boomerang {
+            continue;
+        }
         if transform.translation.x.abs() > half_width || transform.translation.y.abs() > half_height
         {
             commands.entity(entity).despawn();
@@ -485,13 +862,9 @@ fn update_rocket_bullets(
     time: Res<Time>,
     config: Res<GameConfig>,
     mut rocket_query: Query<(Entity, &mut Transform, &mut WeaponBullet, &mut RocketBullet)>,
-    boss_state: Res<super::BossState>,
-    mut enemy_set: ParamSet<(
-        // 明确排除 RocketBullet，确保与 rocket_query 的 Transform 可变借用不重叠（B0001）
-        Query<(Entity, &Transform), (With<Enemy>, Without<RocketBullet>)>,
-        Query<&mut Enemy>,
-    )>,
-    mut game_data: ResMut<GameData>,
+    // 明确排除 RocketBullet，确保与 rocket_query 的 Transform 可变借用不重叠（B0001）
+    targets: Query<(Entity, &Transform), (With<Health>, Without<RocketBullet>)>,
+    mut damage_events: MessageWriter<DamageEvent>,
 ) {
     let delta = time.delta_secs();
     let half_height = config.window_height / 2.0 + 50.0;
@@ -503,8 +876,7 @@ fn update_rocket_bullets(
         if !rocket.initialized {
             let mut rng = rand::rng();
             let direction = if let Some(target) = rocket.target {
-                enemy_set
-                    .p0()
+                targets
                     .get(target)
                     .ok()
                     .map(|(_, t)| {
@@ -536,40 +908,18 @@ fn update_rocket_bullets(
         let timed_out = bullet.lifetime <= 0.0;
 
         if out_of_bounds || timed_out {
-            let targets: Vec<Entity> = {
-                let center2 = transform.translation.truncate();
-                let r2 = rocket.explosion_radius * rocket.explosion_radius;
-                enemy_set
-                    .p0()
-                    .iter()
-                    .filter_map(|(e, t)| {
-                        (t.translation.truncate().distance_squared(center2) <= r2).then_some(e)
-                    })
-                    .collect()
-            };
-            for enemy_entity in targets {
-                if let Ok(mut enemy) = enemy_set.p1().get_mut(enemy_entity) {
-                    enemy.health -= bullet.damage;
-                    if enemy.health <= 0 {
-                        let score = enemy.score_value;
-                        let position = enemy_set
-                            .p0()
-                            .get(enemy_entity)
-                            .map(|(_, t)| t.translation)
-                            .unwrap_or(transform.translation);
-                        commands.entity(enemy_entity).despawn();
-                        if boss_state.active {
-                            game_data.add_score_only(score);
-                        } else {
-                            game_data.add_score(score);
-                            // 2% 概率掉落金币
-                            let mut rng = rand::rng();
-                            if rng.random_bool(0.02) {
-                                use crate::entities::shield::{spawn_power_up, PowerUpType};
-                                spawn_power_up(&mut commands, position, PowerUpType::Coin);
-                            }
-                        }
-                    }
+            let center = transform.translation.truncate();
+            let r2 = rocket.explosion_radius * rocket.explosion_radius;
+            for (target, target_tf) in targets.iter() {
+                if target_tf.translation.truncate().distance_squared(center) <= r2 {
+                    damage_events.write(DamageEvent {
+                        source: Some(entity),
+                        target,
+                        amount: bullet.damage,
+                        weapon: Some(bullet.weapon_type),
+                        position: target_tf.translation,
+                        sub_shape: None,
+                    });
                 }
             }
             // 爆炸：发射很多小三角碎片
@@ -589,12 +939,15 @@ fn update_rocket_bullets(
 fn update_aura_orbs(
     mut commands: Commands,
     time: Res<Time>,
-    player_query: Query<(Entity, &Transform, &WeaponInventory), With<Player>>,
-    mut orb_query: Query<(Entity, &mut Transform, &mut AuraOrb), Without<Player>>,
+    player_query: Query<(Entity, &Transform, &WeaponInventory, Ref<StatModifiers>), With<Player>>,
+    mut orb_query: Query<
+        (Entity, &mut Transform, &mut AuraOrb, &mut WeaponBullet),
+        Without<Player>,
+    >,
 ) {
-    let Ok((_, player_transform, inventory)) = player_query.single() else {
+    let Ok((_, player_transform, inventory, stats)) = player_query.single() else {
         // 没有玩家，销毁所有光球
-        for (entity, _, _) in orb_query.iter() {
+        for (entity, ..) in orb_query.iter() {
             commands.entity(entity).despawn();
         }
         return;
@@ -608,7 +961,7 @@ fn update_aura_orbs(
 
     let Some(aura) = aura_weapon else {
         // 没有护身光球武器，销毁现有光球
-        for (entity, _, _) in orb_query.iter() {
+        for (entity, ..) in orb_query.iter() {
             commands.entity(entity).despawn();
         }
         return;
@@ -624,7 +977,11 @@ fn update_aura_orbs(
 
     // 更新现有光球
     let mut existing_count = 0;
-    for (_, mut transform, mut orb) in orb_query.iter_mut() {
+    for (_, mut transform, mut orb, mut bullet) in orb_query.iter_mut() {
+        // 光球常驻，属性变化时重新结算伤害
+        if stats.is_changed() {
+            bullet.damage = stats.scale_damage(1);
+        }
         orb.orbit_angle += orbit_speed * delta;
         orb.orbit_radius = orbit_radius;
 
@@ -665,7 +1022,7 @@ fn update_aura_orbs(
             commands.entity(entity).insert((
                 WeaponBullet {
                     weapon_type: WeaponType::Aura,
-                    damage: 1,
+                    damage: stats.scale_damage(1),
                     velocity: Vec2::ZERO,
                     lifetime: f32::MAX,
                 },
@@ -678,17 +1035,145 @@ fn update_aura_orbs(
                     orbit_speed,
                     orbit_radius,
                 },
-                Collider::new(blueprint.collision.clone(), CollisionLayer::PlayerBullet).with_mask(
-                    CollisionMask {
-                        enemy_bullet: true,
-                        ..CollisionMask::player_bullet_mask()
-                    },
-                ),
+                Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
+                    .with_mask(
+                        CollisionMask::player_bullet_mask().with(CollisionLayer::ENEMY_BULLET),
+                    ),
             ));
         }
     }
 }
 
+/// 更新激光光束：跟随机头，向上检测阻挡物，记录光束上的目标
+fn update_laser_beam(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    fire_control: Res<FireControl>,
+    player_query: Query<(&Transform, &WeaponInventory), With<Player>>,
+    mut beam_query: Query<
+        (Entity, &mut LaserBeam, &mut Transform, &mut Visibility),
+        Without<Player>,
+    >,
+    // 使用 GlobalTransform 读取目标，避免与光束的 Transform 可变借用冲突（B0001）
+    targets: Query<(Entity, &GlobalTransform, &Collider), With<Health>>,
+) {
+    let laser = player_query
+        .single()
+        .ok()
+        .and_then(|(transform, inventory)| {
+            inventory
+                .weapons
+                .iter()
+                .find(|w| matches!(w.weapon_type, WeaponType::Laser | WeaponType::ArcLaser))
+                .map(|weapon| {
+                    let evolved = weapon.weapon_type == WeaponType::ArcLaser;
+                    (transform.translation, weapon.level, evolved)
+                })
+        });
+    let Some((player_pos, level, evolved)) = laser else {
+        // 没有玩家或没有激光，销毁光束
+        for (entity, ..) in beam_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let muzzle = player_pos + Vec3::new(0.0, LASER_MUZZLE_OFFSET, -1.0);
+    let Ok((entity, mut beam, mut transform, mut visibility)) = beam_query.single_mut() else {
+        spawn_laser(&mut commands, muzzle, level, evolved);
+        return;
+    };
+    // 升级或进化后重建光束（宽度、颜色变化）
+    if beam.level != level || beam.evolved != evolved {
+        commands.entity(entity).despawn();
+        spawn_laser(&mut commands, muzzle, level, evolved);
+        return;
+    }
+
+    // 手动模式下仅在按住射击键时发射
+    if fire_control.manual && !fire_control.held {
+        *visibility = Visibility::Hidden;
+        beam.hits.clear();
+        return;
+    }
+
+    // 射线检测：光束宽度内、机头前方的目标，按进入光束的距离排序。
+    // 先用外接圆粗筛，再按精确碰撞形状求进入距离
+    let max_length = (config.window_height / 2.0 + 20.0 - muzzle.y).max(0.0);
+    let half_width = beam.width / 2.0;
+    let origin = muzzle.truncate();
+    let mut hits: Vec<(Entity, f32)> = targets
+        .iter()
+        .filter_map(|(target, global, collider)| {
+            let transform = global.compute_transform();
+            let (pos, scale) = (transform.translation, transform.scale);
+            let radius = collider.bounding_radius() * scale.abs().max_element();
+            let dx = ((pos.x - muzzle.x).abs() - half_width).max(0.0);
+            if dx > radius || pos.y + radius < muzzle.y || pos.y - radius > muzzle.y + max_length {
+                return None;
+            }
+            collider
+                .beam_entry(&transform, origin, Vec2::Y, half_width, max_length)
+                .map(|entry| (target, entry))
+        })
+        .collect();
+    hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+    if !beam.pierces() {
+        hits.truncate(1);
+    }
+
+    beam.length = match hits.first() {
+        Some((_, entry)) if !beam.pierces() => *entry,
+        _ => max_length,
+    };
+    transform.translation = muzzle;
+    transform.scale = Vec3::new(1.0, beam.length.max(0.001), 1.0);
+    *visibility = Visibility::Inherited;
+    beam.hits = hits;
+}
+
+/// 激光按固定间隔对光束上的目标结算伤害，并在命中点生成火花；
+/// 电弧激光另外周期性从最近的命中点迸发闪电链
+fn apply_laser_damage(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_query: Query<&StatModifiers, With<Player>>,
+    mut beam_query: Query<(Entity, &Transform, &mut LaserBeam)>,
+    mut damage_events: MessageWriter<DamageEvent>,
+) {
+    let stats = player_query.single().copied().unwrap_or_default();
+    for (entity, transform, mut beam) in beam_query.iter_mut() {
+        if beam.evolved {
+            beam.arc_timer -= time.delta_secs();
+            let first_hit = beam.hits.first().map(|&(_, entry)| entry);
+            if let Some(entry) = first_hit.filter(|_| beam.arc_timer <= 0.0) {
+                beam.arc_timer = ARC_LASER_INTERVAL;
+                let position = transform.translation + Vec3::new(0.0, entry, 0.0);
+                let level = ARC_LASER_LIGHTNING_LEVEL;
+                spawn_lightning(&mut commands, position, level, None, &stats);
+            }
+        }
+
+        beam.tick_timer = (beam.tick_timer - time.delta_secs()).max(-LASER_TICK_INTERVAL);
+        if beam.tick_timer > 0.0 || beam.hits.is_empty() {
+            continue;
+        }
+        beam.tick_timer += stats.scale_cooldown(LASER_TICK_INTERVAL);
+        for &(target, entry) in &beam.hits {
+            let position = transform.translation + Vec3::new(0.0, entry, 0.0);
+            damage_events.write(DamageEvent {
+                source: Some(entity),
+                target,
+                amount: stats.scale_damage(beam.tick_damage()),
+                weapon: Some(beam.weapon_type()),
+                position,
+                sub_shape: None,
+            });
+            spawn_laser_sparks(&mut commands, position, beam.width);
+        }
+    }
+}
+
 /// 更新自导导弹
 fn update_homing_missiles(
     time: Res<Time>,
@@ -735,10 +1220,8 @@ fn update_homing_missiles(
 fn resolve_lightning_casts(
     mut commands: Commands,
     mut casts: Query<(Entity, &Transform, &LightningCast)>,
-    mut enemy_set: ParamSet<(Query<(Entity, &Transform), With<Enemy>>, Query<&mut Enemy>)>,
-    mut boss_set: ParamSet<(Query<(Entity, &Transform), With<Boss>>, Query<&mut Boss>)>,
-    mut boss_state: ResMut<BossState>,
-    mut game_data: ResMut<GameData>,
+    targets: Query<(Entity, &Transform), With<Health>>,
+    mut damage_events: MessageWriter<DamageEvent>,
 ) {
     fn lightning_shapes_for_path(path: &[(Vec2, Vec2)]) -> Vec<crate::geometry::GeometryShape> {
         use crate::geometry::{GeometryShape, ShapeColor, Vec2D};
@@ -816,22 +1299,8 @@ fn resolve_lightning_casts(
         let mut segments: Vec<(Vec2, Vec2)> = Vec::new();
 
         while remaining > 0 {
-            let next_enemy = enemy_set
-                .p0()
-                .iter()
-                .filter(|(e, t)| {
-                    !hit.iter().any(|(he, _)| he == e)
-                        && t.translation.truncate().distance(current) <= cast.range
-                })
-                .min_by(|(_, a), (_, b)| {
-                    let da = a.translation.truncate().distance_squared(current);
-                    let db = b.translation.truncate().distance_squared(current);
-                    da.partial_cmp(&db).unwrap()
-                })
-                .map(|(e, t)| (e, t.translation.truncate()));
-
-            let next_boss = boss_set
-                .p0()
+            // 敌人与 Boss 都可被闪电链命中
+            let next = targets
                 .iter()
                 .filter(|(e, t)| {
                     !hit.iter().any(|(he, _)| he == e)
@@ -844,17 +1313,6 @@ fn resolve_lightning_casts(
                 })
                 .map(|(e, t)| (e, t.translation.truncate()));
 
-            let next = match (next_enemy, next_boss) {
-                (None, None) => None,
-                (Some(v), None) => Some(v),
-                (None, Some(v)) => Some(v),
-                (Some(a), Some(b)) => {
-                    let da = a.1.distance_squared(current);
-                    let db = b.1.distance_squared(current);
-                    if da <= db { Some(a) } else { Some(b) }
-                }
-            };
-
             let Some((enemy_entity, enemy_pos)) = next else {
                 break;
             };
@@ -867,36 +1325,14 @@ fn resolve_lightning_casts(
 
         // 结算伤害
         for (enemy_entity, enemy_pos) in &hit {
-            if let Ok(mut enemy) = enemy_set.p1().get_mut(*enemy_entity) {
-                enemy.health -= cast.damage;
-                if enemy.health <= 0 {
-                    let score = enemy.score_value;
-                    let position = Vec3::new(enemy_pos.x, enemy_pos.y, 0.0);
-                    commands.entity(*enemy_entity).despawn();
-                    if boss_state.active {
-                        game_data.add_score_only(score);
-                    } else {
-                        game_data.add_score(score);
-                        // 2% 概率掉落金币
-                        let mut rng = rand::rng();
-                        if rng.random_bool(0.02) {
-                            use crate::entities::shield::{spawn_power_up, PowerUpType};
-                            spawn_power_up(&mut commands, position, PowerUpType::Coin);
-                        }
-                    }
-                }
-            } else if let Ok(mut boss) = boss_set.p1().get_mut(*enemy_entity) {
-                boss.health -= cast.damage;
-                boss_state.current_health = boss.health;
-                if boss.health <= 0 {
-                    let score = boss.score_value;
-                    commands.entity(*enemy_entity).despawn();
-                    game_data.add_score(score);
-                    boss_state.active = false;
-                    boss_state.current_health = 0;
-                    log::info!("Boss defeated! Score: {}", score);
-                }
-            }
+            damage_events.write(DamageEvent {
+                source: Some(cast_entity),
+                target: *enemy_entity,
+                amount: cast.damage,
+                weapon: Some(WeaponType::Lightning),
+                position: enemy_pos.extend(0.0),
+                sub_shape: None,
+            });
         }
 
         // 生成视觉效果（线段）
@@ -937,10 +1373,10 @@ fn handle_player_bullet_vs_enemy_bullet(
 ) {
     for event in collision_events.read() {
         let (player_bullet, enemy_bullet) = match (event.layer_a, event.layer_b) {
-            (CollisionLayer::PlayerBullet, CollisionLayer::EnemyBullet) => {
+            (CollisionLayer::PLAYER_BULLET, CollisionLayer::ENEMY_BULLET) => {
                 (event.entity_a, event.entity_b)
             }
-            (CollisionLayer::EnemyBullet, CollisionLayer::PlayerBullet) => {
+            (CollisionLayer::ENEMY_BULLET, CollisionLayer::PLAYER_BULLET) => {
                 (event.entity_b, event.entity_a)
             }
             _ => continue,
@@ -1003,15 +1439,15 @@ fn player_collision_handler(
     mut game_data: ResMut<GameData>,
     mut floating_score_events: MessageWriter<crate::ui::FloatingScoreEvent>,
     mut next_state: ResMut<NextState<GameState>>,
-    mut player_query: Query<&mut Player>,
+    mut player_query: Query<(&mut Player, &Transform)>,
     power_up_query: Query<&crate::entities::shield::PowerUp>,
-    transforms: Query<&Transform>,
+    mut events: GameplayEvents,
 ) {
     for event in collision_events.read() {
         // 检查是否涉及玩家
-        let player_entity = if event.layer_a == CollisionLayer::Player {
+        let player_entity = if event.layer_a == CollisionLayer::PLAYER {
             Some(event.entity_a)
-        } else if event.layer_b == CollisionLayer::Player {
+        } else if event.layer_b == CollisionLayer::PLAYER {
             Some(event.entity_b)
         } else {
             None
@@ -1021,9 +1457,10 @@ fn player_collision_handler(
             continue;
         };
 
-        let Ok(mut player) = player_query.get_mut(player_entity) else {
+        let Ok((mut player, player_tf)) = player_query.get_mut(player_entity) else {
             continue;
         };
+        let player_pos = player_tf.translation;
 
         // 如果玩家无敌，跳过
         if player.invincible {
@@ -1031,29 +1468,29 @@ fn player_collision_handler(
         }
 
         // 确定另一个实体的类型
-        let other_layer = if event.layer_a == CollisionLayer::Player {
+        let other_layer = if event.layer_a == CollisionLayer::PLAYER {
             event.layer_b
         } else {
             event.layer_a
         };
 
-        let other_entity = if event.layer_a == CollisionLayer::Player {
+        let other_entity = if event.layer_a == CollisionLayer::PLAYER {
             event.entity_b
         } else {
             event.entity_a
         };
 
         match other_layer {
-            CollisionLayer::Enemy | CollisionLayer::EnemyBullet => {
-                // 命中火花：优先取子弹/敌人位置，否则退化为玩家位置
-                let spark_pos = transforms
-                    .get(player_entity)
-                    .map(|t| t.translation)
-                    .unwrap_or_default();
-                crate::entities::spawn_hit_sparks(&mut commands, spark_pos);
+            CollisionLayer::ENEMY | CollisionLayer::ENEMY_BULLET => {
+                // 命中火花
+                crate::entities::spawn_hit_sparks(&mut commands, player_pos);
+
+                // 受到攻击即中断连击（护盾吸收也算）
+                game_data.break_combo();
 
                 // 玩家受伤 - 先扣护盾，再扣血
-                if game_data.shield > 0 {
+                let shield_absorbed = game_data.shield > 0;
+                if shield_absorbed {
                     game_data.shield -= 1;
                     log::info!("Shield absorbed damage! Shield: {}", game_data.shield);
                 } else if game_data.lives > 0 {
@@ -1062,9 +1499,15 @@ fn player_collision_handler(
                     player.invincible_timer = 2.0; // 2秒无敌时间
                     log::info!("Player hit! Lives remaining: {}", game_data.lives);
                 }
+                events.player_damaged.write(PlayerDamaged {
+                    player: player_entity,
+                    source: other_entity,
+                    shield_absorbed,
+                    lives_left: game_data.lives,
+                });
 
                 // 销毁敌人子弹
-                if other_layer == CollisionLayer::EnemyBullet {
+                if other_layer == CollisionLayer::ENEMY_BULLET {
                     commands.entity(other_entity).despawn();
                 }
 
@@ -1072,7 +1515,7 @@ fn player_collision_handler(
                     next_state.set(GameState::GameOver);
                 }
             }
-            CollisionLayer::PowerUp => {
+            CollisionLayer::POWER_UP => {
                 let power_type = power_up_query
                     .get(other_entity)
                     .map(|p| p.power_type)
@@ -1086,10 +1529,7 @@ fn player_collision_handler(
                     Some(crate::entities::shield::PowerUpType::Shield) => {
                         if game_data.shield >= game_data.max_shield {
                             game_data.add_score_only(1000);
-                            let pos = transforms
-                                .get(player_entity)
-                                .map(|t| t.translation + Vec3::new(0.0, 60.0, 0.0))
-                                .unwrap_or(Vec3::new(0.0, 60.0, 0.0));
+                            let pos = player_pos + Vec3::new(0.0, 60.0, 0.0);
                             floating_score_events.write(crate::ui::FloatingScoreEvent {
                                 world_pos: pos,
                                 points: 1000,
@@ -1107,10 +1547,7 @@ fn player_collision_handler(
                     Some(crate::entities::shield::PowerUpType::ExtraLife) => {
                         if game_data.lives >= game_data.max_lives {
                             game_data.add_score_only(1000);
-                            let pos = transforms
-                                .get(player_entity)
-                                .map(|t| t.translation + Vec3::new(0.0, 60.0, 0.0))
-                                .unwrap_or(Vec3::new(0.0, 60.0, 0.0));
+                            let pos = player_pos + Vec3::new(0.0, 60.0, 0.0);
                             floating_score_events.write(crate::ui::FloatingScoreEvent {
                                 world_pos: pos,
                                 points: 1000,
@@ -1129,11 +1566,25 @@ fn player_collision_handler(
                         game_data.upgrading = true;
                         log::info!("Weapon upgrade triggered");
                     }
+                    Some(crate::entities::shield::PowerUpType::ScoreGem) => {
+                        game_data.add_score_only(SCORE_GEM_POINTS);
+                        floating_score_events.write(crate::ui::FloatingScoreEvent {
+                            world_pos: player_pos + Vec3::new(0.0, 40.0, 0.0),
+                            points: SCORE_GEM_POINTS,
+                        });
+                    }
                     None => {
                         // 兼容旧版本：没有 PowerUp 组件也当作小收益
                         game_data.coins += 1;
                     }
                 }
+                if let Some(power_type) = power_type {
+                    events.power_up_collected.write(PowerUpCollected {
+                        player: player_entity,
+                        power_type,
+                        position: player_pos,
+                    });
+                }
 
                 commands.entity(other_entity).despawn();
             }
@@ -1142,6 +1593,19 @@ fn player_collision_handler(
     }
 }
 
+/// 擦弹结算：加分加经验、火花、计入本局统计
+fn handle_grazes(
+    mut commands: Commands,
+    mut graze_events: MessageReader<GrazeEvent>,
+    mut game_data: ResMut<GameData>,
+) {
+    for event in graze_events.read() {
+        game_data.add_score(GRAZE_SCORE);
+        game_data.run_stats.grazes += 1;
+        crate::entities::spawn_graze_spark(&mut commands, event.position.extend(0.0));
+    }
+}
+
 /// 更新无敌状态
 fn update_invincibility(
     time: Res<Time>,
@@ -48,7 +48,7 @@ pub fn spawn_player_shield(commands: &mut Commands, player_entity: Entity) {
             Shield::default(),
             Collider::new(
                 CollisionShape::Circle { radius: 25.0 },
-                CollisionLayer::Player,
+                CollisionLayer::PLAYER,
             )
             .with_mask(CollisionMask::player_mask()),
         ));
@@ -147,6 +147,8 @@ pub enum PowerUpType {
     WeaponUpgrade,
     /// 金币
     Coin,
+    /// 积分宝石（Boss 阶段切换时由敌弹转换而来）
+    ScoreGem,
 }
 
 /// 生成道具
@@ -156,12 +158,13 @@ pub fn spawn_power_up(commands: &mut Commands, position: Vec3, power_type: Power
         PowerUpType::Shield => GeometryBlueprint::power_up_shield(),
         PowerUpType::ExtraLife => GeometryBlueprint::power_up_heart(),
         PowerUpType::WeaponUpgrade => GeometryBlueprint::power_up(),
+        PowerUpType::ScoreGem => GeometryBlueprint::power_up_score_gem(),
     };
     let entity = spawn_geometry_entity(commands, &blueprint, position);
 
     commands.entity(entity).insert((
         PowerUp { power_type },
-        Collider::new(blueprint.collision.clone(), CollisionLayer::PowerUp)
+        Collider::new(blueprint.collision.clone(), CollisionLayer::POWER_UP)
             .with_mask(CollisionMask::default()),
         crate::game::Scrollable::default(),
     ));
@@ -0,0 +1,283 @@
+//! 特殊武器
+//! 回旋刃折返、感应雷触发、火焰膨胀与燃烧、引力井牵引与坍缩的运行逻辑
+//! （生成函数与组件定义见 weapons.rs）
+
+use bevy::prelude::*;
+
+use crate::game::{not_upgrading, GameState};
+
+use super::weapons::{
+    spawn_rocket_explosion_particles, BoomerangBlade, Burning, FlamePuff, GravityWellOrb, HitList,
+    Ignite, ProximityMine, WeaponBullet, WeaponType, BURN_TICK_INTERVAL,
+    GRAVITY_WELL_BLUEPRINT_RADIUS, GRAVITY_WELL_TICK_INTERVAL,
+};
+use super::{DamageEvent, Enemy, Health, Player};
+
+/// 特殊武器插件
+pub struct SpecialWeaponPlugin;
+
+impl Plugin for SpecialWeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                update_boomerang_blades,
+                update_proximity_mines,
+                update_flame_puffs,
+                ignite_burning_targets,
+                update_burning,
+                update_gravity_wells,
+            )
+                .run_if(in_state(GameState::Playing))
+                .run_if(not_upgrading),
+        );
+    }
+}
+
+/// 回旋刃旋转速度（弧度/秒）
+const BOOMERANG_SPIN: f32 = 14.0;
+/// 回旋刃飞回到该距离内即回收
+const BOOMERANG_CATCH_RADIUS: f32 = 24.0;
+/// 引力井核心（按半径比例）内的目标受到双倍伤害
+const GRAVITY_WELL_CORE_RATIO: f32 = 0.35;
+
+/// 回旋刃：去程逐渐减速，到达射程后折返飞向战机（折返时清空命中记录，可再次命中）
+fn update_boomerang_blades(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_query: Query<&Transform, With<Player>>,
+    mut blades: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut WeaponBullet,
+            &mut BoomerangBlade,
+        ),
+        Without<Player>,
+    >,
+) {
+    let delta = time.delta_secs();
+    let player_pos = player_query
+        .single()
+        .ok()
+        .map(|tf| tf.translation.truncate());
+
+    for (entity, mut transform, mut bullet, mut blade) in blades.iter_mut() {
+        transform.rotate_z(BOOMERANG_SPIN * delta);
+
+        if !blade.returning {
+            blade.traveled += bullet.velocity.length() * delta;
+            let progress = (blade.traveled / blade.range).min(1.0);
+            let direction = bullet.velocity.normalize_or(Vec2::Y);
+            bullet.velocity = direction * blade.speed * (1.0 - 0.7 * progress);
+            if progress >= 1.0 {
+                blade.returning = true;
+                commands.entity(entity).insert(HitList::default());
+            }
+            continue;
+        }
+
+        // 没有玩家时沿当前方向飞出屏幕
+        let Some(player_pos) = player_pos else {
+            continue;
+        };
+        let offset = player_pos - transform.translation.truncate();
+        if offset.length() <= BOOMERANG_CATCH_RADIUS {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        bullet.velocity = offset.normalize() * blade.speed;
+    }
+}
+
+/// 感应雷：激活后检测附近敌人，触发时对爆炸范围内所有目标造成伤害
+fn update_proximity_mines(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut mines: Query<(Entity, &Transform, &WeaponBullet, &mut ProximityMine)>,
+    targets: Query<(Entity, &Transform), With<Health>>,
+    mut damage_events: MessageWriter<DamageEvent>,
+) {
+    for (entity, transform, bullet, mut mine) in mines.iter_mut() {
+        if mine.arm_timer > 0.0 {
+            mine.arm_timer -= time.delta_secs();
+            continue;
+        }
+
+        let center = transform.translation.truncate();
+        let triggered = targets
+            .iter()
+            .any(|(_, tf)| tf.translation.truncate().distance(center) <= mine.trigger_radius);
+        if !triggered {
+            continue;
+        }
+
+        for (target, tf) in targets.iter() {
+            if tf.translation.truncate().distance(center) <= mine.blast_radius {
+                damage_events.write(DamageEvent {
+                    source: Some(entity),
+                    target,
+                    amount: bullet.damage,
+                    weapon: Some(WeaponType::Mine),
+                    position: tf.translation,
+                    sub_shape: None,
+                });
+            }
+        }
+        let shard_count = ((mine.blast_radius / 4.0) as u32).clamp(10, 28);
+        spawn_rocket_explosion_particles(&mut commands, transform.translation, shard_count, 260.0);
+        commands.entity(entity).despawn();
+    }
+}
+
+/// 火焰随飞行逐渐膨胀
+fn update_flame_puffs(mut puffs: Query<(&WeaponBullet, &FlamePuff, &mut Transform)>) {
+    for (bullet, puff, mut transform) in puffs.iter_mut() {
+        let progress = 1.0 - (bullet.lifetime / puff.max_lifetime).clamp(0.0, 1.0);
+        transform.scale = Vec3::splat(0.6 + 1.4 * progress);
+    }
+}
+
+/// 带点燃效果的来源命中时点燃目标；已在燃烧的目标刷新持续时间
+fn ignite_burning_targets(
+    mut commands: Commands,
+    mut damage_events: MessageReader<DamageEvent>,
+    igniters: Query<&Ignite>,
+    mut burning: Query<&mut Burning>,
+) {
+    for event in damage_events.read() {
+        let Some(ignite) = event.source.and_then(|source| igniters.get(source).ok()) else {
+            continue;
+        };
+        if let Ok(mut burn) = burning.get_mut(event.target) {
+            burn.remaining = burn.remaining.max(ignite.burn_duration);
+            burn.damage = burn.damage.max(ignite.burn_damage);
+        } else {
+            commands.entity(event.target).try_insert(Burning {
+                damage: ignite.burn_damage,
+                remaining: ignite.burn_duration,
+                tick_timer: BURN_TICK_INTERVAL,
+            });
+        }
+    }
+}
+
+/// 燃烧持续伤害
+fn update_burning(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &Transform, &mut Burning)>,
+    mut damage_events: MessageWriter<DamageEvent>,
+) {
+    let delta = time.delta_secs();
+    for (entity, transform, mut burn) in query.iter_mut() {
+        burn.remaining -= delta;
+        burn.tick_timer -= delta;
+        if burn.tick_timer <= 0.0 {
+            burn.tick_timer += BURN_TICK_INTERVAL;
+            damage_events.write(DamageEvent {
+                source: None,
+                target: entity,
+                amount: burn.damage,
+                weapon: Some(WeaponType::Flamethrower),
+                position: transform.translation,
+                sub_shape: None,
+            });
+        }
+        if burn.remaining <= 0.0 {
+            commands.entity(entity).remove::<Burning>();
+        }
+    }
+}
+
+/// 引力井（含炎狱漩涡）：飞行结束后展开，牵引范围内的普通敌人（Boss 不受牵引）并周期性造成伤害，
+/// 持续时间结束时坍缩，对范围内所有目标造成一次爆发伤害
+fn update_gravity_wells(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut wells: Query<(
+        Entity,
+        &mut Transform,
+        &mut WeaponBullet,
+        &mut GravityWellOrb,
+    )>,
+    mut enemies: Query<&mut Transform, (With<Enemy>, Without<GravityWellOrb>)>,
+    targets: Query<(Entity, &GlobalTransform), With<Health>>,
+    mut damage_events: MessageWriter<DamageEvent>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut transform, mut bullet, mut well) in wells.iter_mut() {
+        let full_scale = well.radius / GRAVITY_WELL_BLUEPRINT_RADIUS;
+        transform.rotate_z(-3.0 * delta);
+
+        if well.travel > 0.0 {
+            well.travel -= delta;
+            if well.travel <= 0.0 {
+                bullet.velocity = Vec2::ZERO;
+                transform.scale = Vec3::splat(full_scale);
+            }
+            continue;
+        }
+
+        let center = transform.translation.truncate();
+        well.duration -= delta;
+        if well.duration <= 0.0 {
+            for (target, global) in targets.iter() {
+                let pos = global.translation();
+                if pos.truncate().distance(center) <= well.radius {
+                    damage_events.write(DamageEvent {
+                        source: Some(entity),
+                        target,
+                        amount: well.collapse_damage,
+                        weapon: Some(bullet.weapon_type),
+                        position: pos,
+                        sub_shape: None,
+                    });
+                }
+            }
+            spawn_rocket_explosion_particles(&mut commands, transform.translation, 24, 220.0);
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // 牵引：越靠近中心拉力越强
+        for mut enemy_tf in enemies.iter_mut() {
+            let offset = center - enemy_tf.translation.truncate();
+            let distance = offset.length();
+            if distance > well.radius || distance < 1.0 {
+                continue;
+            }
+            let strength = well.pull * (1.0 - distance / well.radius * 0.5);
+            let step = (strength * delta).min(distance);
+            enemy_tf.translation += (offset / distance * step).extend(0.0);
+        }
+
+        well.tick_timer -= delta;
+        if well.tick_timer > 0.0 {
+            continue;
+        }
+        well.tick_timer += GRAVITY_WELL_TICK_INTERVAL;
+        let core_radius = well.radius * GRAVITY_WELL_CORE_RATIO;
+        for (target, global) in targets.iter() {
+            let pos = global.translation();
+            let distance = pos.truncate().distance(center);
+            if distance > well.radius {
+                continue;
+            }
+            // 核心处碾压：双倍伤害
+            let amount = if distance <= core_radius {
+                bullet.damage * 2
+            } else {
+                bullet.damage
+            };
+            damage_events.write(DamageEvent {
+                source: Some(entity),
+                target,
+                amount,
+                weapon: Some(bullet.weapon_type),
+                position: pos,
+                sub_shape: None,
+            });
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! 攻击预警
+//! 大招出手前闪烁的警示线/警示圈（可在预警结束时发射弹幕），
+//! 以及即将从屏幕上方进场的敌人的顶部箭头提示
+
+use bevy::prelude::*;
+
+use crate::game::{not_upgrading, GameConfig, GameState};
+use crate::geometry::{
+    spawn_geometry_entity, CollisionShape, GeometryBlueprint, GeometryShape, ShapeColor, Vec2D,
+};
+
+use super::bullet_pattern::{fire_pattern, BulletPattern, BulletSource};
+use super::events::BossPhaseChanged;
+use super::Enemy;
+
+/// 攻击预警插件
+pub struct TelegraphPlugin;
+
+impl Plugin for TelegraphPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Menu), despawn_all_telegraphs)
+            .add_systems(OnEnter(GameState::GameOver), despawn_all_telegraphs)
+            .add_systems(OnEnter(GameState::Recharge), despawn_all_telegraphs)
+            .add_systems(
+                Update,
+                (
+                    follow_telegraph_owners,
+                    cancel_telegraphs_on_phase_change,
+                    (tick_telegraphs, fire_telegraph_shots, expire_telegraphs).chain(),
+                    spawn_entry_arrows,
+                    update_entry_arrows,
+                )
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not_upgrading),
+            );
+    }
+}
+
+/// 预警颜色
+const WARNING_COLOR: ShapeColor = ShapeColor::new(1.0, 0.25, 0.2, 0.75);
+/// 预警图形的层级（在敌人之上、子弹之下）
+const TELEGRAPH_Z: f32 = 6.0;
+/// 敌人距屏幕上边缘多远时开始显示进场箭头
+const ENTRY_ARROW_RANGE: f32 = 160.0;
+/// 进场箭头距屏幕上边缘的距离
+const ENTRY_ARROW_INSET: f32 = 16.0;
+
+/// 预警图形：闪烁 `lead` 秒后消失，越接近出手闪得越快
+#[derive(Component)]
+pub struct Telegraph {
+    pub timer: f32,
+    pub lead: f32,
+}
+
+/// 预警结束时发射的弹幕（角度在预警时就已锁定）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TelegraphShot {
+    pub pattern: BulletPattern,
+    pub source: BulletSource,
+}
+
+/// 预警跟随发动者移动；发动者消失时预警（及其弹幕）取消
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TelegraphFollow {
+    pub owner: Entity,
+    pub offset: Vec2,
+}
+
+/// 顶部进场箭头，指向即将进场的敌人
+#[derive(Component)]
+struct EntryArrow {
+    target: Entity,
+    timer: f32,
+}
+
+/// 已显示过进场箭头的敌人
+#[derive(Component)]
+struct EntryWarned;
+
+/// 生成警示线：从 `origin` 沿 `angle` 方向延伸 `length`
+pub fn spawn_telegraph_line(
+    commands: &mut Commands,
+    origin: Vec3,
+    angle: f32,
+    length: f32,
+    lead: f32,
+) -> Entity {
+    let end = Vec2::from_angle(angle) * length;
+    let blueprint = GeometryBlueprint {
+        name: "telegraph_line".to_string(),
+        shapes: vec![GeometryShape::Line {
+            start: Vec2D::ZERO,
+            end: Vec2D::new(end.x, end.y),
+            color: WARNING_COLOR,
+            stroke_width: 2.0,
+        }],
+        collision: CollisionShape::Circle { radius: 1.0 },
+        scale: 1.0,
+    };
+    spawn_telegraph(commands, &blueprint, origin, lead)
+}
+
+/// 生成警示圈
+pub fn spawn_telegraph_circle(
+    commands: &mut Commands,
+    center: Vec3,
+    radius: f32,
+    lead: f32,
+) -> Entity {
+    let blueprint = GeometryBlueprint {
+        name: "telegraph_circle".to_string(),
+        shapes: vec![
+            GeometryShape::Circle {
+                center: Vec2D::ZERO,
+                radius,
+                color: ShapeColor::new(1.0, 0.25, 0.2, 0.15),
+                fill: true,
+                stroke_width: 0.0,
+            },
+            GeometryShape::Circle {
+                center: Vec2D::ZERO,
+                radius,
+                color: WARNING_COLOR,
+                fill: false,
+                stroke_width: 2.0,
+            },
+        ],
+        collision: CollisionShape::Circle { radius },
+        scale: 1.0,
+    };
+    spawn_telegraph(commands, &blueprint, center, lead)
+}
+
+fn spawn_telegraph(
+    commands: &mut Commands,
+    blueprint: &GeometryBlueprint,
+    position: Vec3,
+    lead: f32,
+) -> Entity {
+    let entity = spawn_geometry_entity(commands, blueprint, position.with_z(TELEGRAPH_Z));
+    commands
+        .entity(entity)
+        .insert(Telegraph { timer: lead, lead });
+    entity
+}
+
+/// 预警跟随发动者；发动者已不存在时取消
+fn follow_telegraph_owners(
+    mut commands: Commands,
+    owners: Query<&Transform, Without<Telegraph>>,
+    mut telegraphs: Query<(Entity, &TelegraphFollow, &mut Transform), With<Telegraph>>,
+) {
+    for (entity, follow, mut transform) in telegraphs.iter_mut() {
+        let Ok(owner) = owners.get(follow.owner) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let position = owner.translation.truncate() + follow.offset;
+        transform.translation = position.extend(TELEGRAPH_Z);
+    }
+}
+
+/// Boss 进入新阶段时取消其尚未出手的攻击
+fn cancel_telegraphs_on_phase_change(
+    mut commands: Commands,
+    mut phase_changed: MessageReader<BossPhaseChanged>,
+    telegraphs: Query<(Entity, &TelegraphFollow)>,
+) {
+    for event in phase_changed.read() {
+        for (entity, follow) in telegraphs.iter() {
+            if follow.owner == event.entity {
+                commands.entity(entity).try_despawn();
+            }
+        }
+    }
+}
+
+/// 预警计时与闪烁
+fn tick_telegraphs(time: Res<Time>, mut telegraphs: Query<(&mut Telegraph, &mut Visibility)>) {
+    for (mut telegraph, mut visibility) in telegraphs.iter_mut() {
+        telegraph.timer -= time.delta_secs();
+        // 闪烁频率从 4Hz 逐渐加快到 12Hz
+        let progress = 1.0 - (telegraph.timer / telegraph.lead.max(0.01)).clamp(0.0, 1.0);
+        let frequency = 4.0 + progress * 8.0;
+        *visibility = if (telegraph.timer * frequency).fract() < 0.5 {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// 预警结束：发射锁定的弹幕
+fn fire_telegraph_shots(
+    mut commands: Commands,
+    telegraphs: Query<(&Telegraph, &Transform, &TelegraphShot)>,
+) {
+    for (telegraph, transform, shot) in telegraphs.iter() {
+        if telegraph.timer <= 0.0 {
+            fire_pattern(
+                &mut commands,
+                &shot.pattern,
+                shot.source,
+                transform.translation,
+                None,
+                0,
+            );
+        }
+    }
+}
+
+fn expire_telegraphs(mut commands: Commands, telegraphs: Query<(Entity, &Telegraph)>) {
+    for (entity, telegraph) in telegraphs.iter() {
+        if telegraph.timer <= 0.0 {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
+
+/// 敌人即将从上方进场时在屏幕顶部显示箭头
+fn spawn_entry_arrows(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    enemies: Query<(Entity, &Transform, Has<EntryWarned>), With<Enemy>>,
+) {
+    let top = config.window_height / 2.0;
+    for (entity, transform, warned) in enemies.iter() {
+        let y = transform.translation.y;
+        if warned || y <= top || y > top + ENTRY_ARROW_RANGE {
+            continue;
+        }
+        let position = Vec3::new(
+            transform.translation.x,
+            top - ENTRY_ARROW_INSET,
+            TELEGRAPH_Z,
+        );
+        let arrow = spawn_geometry_entity(&mut commands, &entry_arrow_blueprint(), position);
+        commands.entity(arrow).insert(EntryArrow {
+            target: entity,
+            timer: 0.0,
+        });
+        commands.entity(entity).insert(EntryWarned);
+    }
+}
+
+/// 箭头跟随敌人横坐标并闪烁，敌人进场或消失后移除
+fn update_entry_arrows(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    enemies: Query<&Transform, (With<Enemy>, Without<EntryArrow>)>,
+    mut arrows: Query<(Entity, &mut EntryArrow, &mut Transform)>,
+    mut visibilities: Query<&mut Visibility, With<EntryArrow>>,
+) {
+    let top = config.window_height / 2.0;
+    for (entity, mut arrow, mut transform) in arrows.iter_mut() {
+        let Ok(target) = enemies.get(arrow.target) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if target.translation.y <= top {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        arrow.timer += time.delta_secs();
+        transform.translation.x = target.translation.x;
+        if let Ok(mut visibility) = visibilities.get_mut(entity) {
+            *visibility = if (arrow.timer * 5.0).fract() < 0.6 {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
+}
+
+/// 进场箭头（朝下的三角形）
+fn entry_arrow_blueprint() -> GeometryBlueprint {
+    GeometryBlueprint {
+        name: "entry_arrow".to_string(),
+        shapes: vec![GeometryShape::Polygon {
+            vertices: vec![
+                Vec2D::new(-9.0, 6.0),
+                Vec2D::new(9.0, 6.0),
+                Vec2D::new(0.0, -8.0),
+            ],
+            color: ShapeColor::new(1.0, 0.6, 0.2, 0.9),
+            fill: true,
+            stroke_width: 1.5,
+        }],
+        collision: CollisionShape::Circle { radius: 8.0 },
+        scale: 1.0,
+    }
+}
+
+fn despawn_all_telegraphs(
+    mut commands: Commands,
+    telegraphs: Query<Entity, With<Telegraph>>,
+    arrows: Query<Entity, With<EntryArrow>>,
+) {
+    for entity in telegraphs.iter().chain(arrows.iter()) {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,15 +1,18 @@
 //! 武器系统
-//! 实现各种肉鸽武器：霰弹枪、导弹、激光、自导导弹、闪电链、护身光球、光柱
+//! 实现各种肉鸽武器：霰弹枪、导弹、激光、自导导弹、闪电链、护身光球、光柱、
+//! 回旋刃、感应雷、火焰喷射器、引力井；两把满级武器可按配方进化为更强的进化武器
 
 use bevy::prelude::*;
 use rand::Rng;
 use std::f32::consts::PI;
 
-use crate::game::{Collider, CollisionLayer, CollisionMask, GameConfig};
+use crate::game::{Collider, CollisionLayer, CollisionMask, GameConfig, SweptCollider};
 use crate::geometry::{
     spawn_geometry_entity, CollisionShape, GeometryBlueprint, GeometryShape, ShapeColor, Vec2D,
 };
 
+use super::passives::StatModifiers;
+
 /// 武器等级上限
 pub const MAX_WEAPON_LEVEL: u32 = 8;
 
@@ -20,7 +23,7 @@ pub enum WeaponType {
     Shotgun,
     /// R: 导弹 - 随机向敌人直线飞去
     Rocket,
-    /// L: 激光 - 穿透敌人的细长激光
+    /// L: 激光 - 从机头射出的持续光束
     Laser,
     /// H: 自导导弹 - 弧线跟踪
     Homing,
@@ -30,6 +33,20 @@ pub enum WeaponType {
     Aura,
     /// C: 光柱 - 从屏幕后方推进
     Beam,
+    /// O: 回旋刃 - 飞出后折返，往返均可命中
+    Boomerang,
+    /// M: 感应雷 - 布置在战机后方，敌人靠近时爆炸
+    Mine,
+    /// F: 火焰喷射器 - 短程锥形火焰，使敌人持续燃烧
+    Flamethrower,
+    /// G: 引力井 - 吸引并碾压范围内的敌人
+    GravityWell,
+    /// X: 电弧激光（激光 + 闪电链进化）- 贯穿光束，命中处迸发闪电链
+    ArcLaser,
+    /// W: 蜂群导弹（导弹 + 自导导弹进化）- 成群追踪导弹，命中爆炸
+    SwarmMissiles,
+    /// V: 炎狱漩涡（火焰喷射器 + 引力井进化）- 更大的引力井，点燃范围内的敌人
+    InfernoVortex,
 }
 
 impl WeaponType {
@@ -43,6 +60,13 @@ impl WeaponType {
             WeaponType::Lightning => "闪电链",
             WeaponType::Aura => "护身光球",
             WeaponType::Beam => "光柱",
+            WeaponType::Boomerang => "回旋刃",
+            WeaponType::Mine => "感应雷",
+            WeaponType::Flamethrower => "火焰喷射器",
+            WeaponType::GravityWell => "引力井",
+            WeaponType::ArcLaser => "电弧激光",
+            WeaponType::SwarmMissiles => "蜂群导弹",
+            WeaponType::InfernoVortex => "炎狱漩涡",
         }
     }
 
@@ -56,10 +80,17 @@ impl WeaponType {
             WeaponType::Lightning => 'B',
             WeaponType::Aura => 'A',
             WeaponType::Beam => 'C',
+            WeaponType::Boomerang => 'O',
+            WeaponType::Mine => 'M',
+            WeaponType::Flamethrower => 'F',
+            WeaponType::GravityWell => 'G',
+            WeaponType::ArcLaser => 'X',
+            WeaponType::SwarmMissiles => 'W',
+            WeaponType::InfernoVortex => 'V',
         }
     }
 
-    /// 所有武器类型列表
+    /// 所有武器类型列表（不含进化武器，进化武器只能通过配方获得）
     pub fn all() -> &'static [WeaponType] {
         &[
             WeaponType::Shotgun,
@@ -69,10 +100,51 @@ impl WeaponType {
             WeaponType::Lightning,
             WeaponType::Aura,
             WeaponType::Beam,
+            WeaponType::Boomerang,
+            WeaponType::Mine,
+            WeaponType::Flamethrower,
+            WeaponType::GravityWell,
         ]
     }
+
+    /// 进化武器对应的配方；普通武器返回 None
+    pub fn evolution_recipe(&self) -> Option<&'static EvolutionRecipe> {
+        EVOLUTION_RECIPES.iter().find(|r| r.result == *self)
+    }
+
+    /// 是否为进化武器
+    pub fn is_evolved(&self) -> bool {
+        self.evolution_recipe().is_some()
+    }
 }
 
+/// 进化配方：两把满级武器合成一把进化武器（替换原武器）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvolutionRecipe {
+    pub first: WeaponType,
+    pub second: WeaponType,
+    pub result: WeaponType,
+}
+
+/// 所有进化配方
+pub const EVOLUTION_RECIPES: &[EvolutionRecipe] = &[
+    EvolutionRecipe {
+        first: WeaponType::Laser,
+        second: WeaponType::Lightning,
+        result: WeaponType::ArcLaser,
+    },
+    EvolutionRecipe {
+        first: WeaponType::Rocket,
+        second: WeaponType::Homing,
+        result: WeaponType::SwarmMissiles,
+    },
+    EvolutionRecipe {
+        first: WeaponType::Flamethrower,
+        second: WeaponType::GravityWell,
+        result: WeaponType::InfernoVortex,
+    },
+];
+
 /// 单个武器数据
 #[derive(Debug, Clone)]
 pub struct Weapon {
@@ -115,11 +187,20 @@ impl WeaponType {
             // 霰弹枪/跟踪弹：射速与默认子弹接近
             WeaponType::Shotgun => 0.15,
             WeaponType::Rocket => 0.6, // 导弹发射速度翻倍
-            WeaponType::Laser => 0.25,
+            // 激光为持续光束，不按冷却发射
+            WeaponType::Laser => 0.0,
             WeaponType::Homing => 0.15,
             WeaponType::Lightning => 0.5,
             WeaponType::Aura => 0.0, // 被动
             WeaponType::Beam => 2.0,
+            WeaponType::Boomerang => 1.0,
+            WeaponType::Mine => 1.2,
+            WeaponType::Flamethrower => 0.1,
+            WeaponType::GravityWell => 3.5,
+            // 进化武器直接满级，冷却不再随等级缩短
+            WeaponType::ArcLaser => 0.0,
+            WeaponType::SwarmMissiles => 0.9,
+            WeaponType::InfernoVortex => 3.0,
         }
     }
 }
@@ -180,6 +261,35 @@ impl WeaponInventory {
         }
     }
 
+    /// 可进化的配方：两把原武器均已满级
+    pub fn available_evolutions(&self) -> Vec<&'static EvolutionRecipe> {
+        EVOLUTION_RECIPES
+            .iter()
+            .filter(|r| {
+                [r.first, r.second]
+                    .iter()
+                    .all(|wt| self.get_weapon(*wt).is_some_and(Weapon::is_max_level))
+            })
+            .collect()
+    }
+
+    /// 进化：移除两把原武器，加入满级的进化武器
+    pub fn evolve(&mut self, recipe: &EvolutionRecipe) {
+        self.weapons
+            .retain(|w| w.weapon_type != recipe.first && w.weapon_type != recipe.second);
+        let mut weapon = Weapon::new(recipe.result);
+        weapon.level = MAX_WEAPON_LEVEL;
+        self.weapons.push(weapon);
+    }
+
+    /// 武器是否已被进化武器吸收（进化后不再提供原武器）
+    pub fn is_absorbed(&self, weapon_type: WeaponType) -> bool {
+        self.weapons
+            .iter()
+            .filter_map(|w| w.weapon_type.evolution_recipe())
+            .any(|r| r.first == weapon_type || r.second == weapon_type)
+    }
+
     /// 获取可升级的武器列表（用于升级选择）
     pub fn get_upgradeable_weapons(&self) -> Vec<WeaponType> {
         let mut result = Vec::new();
@@ -194,7 +304,7 @@ impl WeaponInventory {
         // 还没有但可以获得的新武器
         if !self.is_full() {
             for wt in WeaponType::all() {
-                if self.get_weapon(*wt).is_none() {
+                if self.get_weapon(*wt).is_none() && !self.is_absorbed(*wt) {
                     result.push(*wt);
                 }
             }
@@ -257,11 +367,64 @@ pub struct RocketBullet {
     pub explosion_radius: f32,
 }
 
-/// 激光
+/// 激光光束：持续存在并跟随战机，每帧从机头向上检测第一个阻挡物
 #[derive(Component)]
 pub struct LaserBeam {
+    /// 生成光束时的武器等级（升级后重建光束以更新宽度）
+    pub level: u32,
+    /// 电弧激光（进化）：始终贯穿、伤害翻倍，并周期性从命中点迸发闪电链
+    pub evolved: bool,
     pub width: f32,
+    /// 当前长度（射线检测结果）
     pub length: f32,
+    /// 光束上的目标及其距光束起点的距离（由近到远）
+    pub hits: Vec<(Entity, f32)>,
+    /// 距下次伤害结算的时间
+    pub tick_timer: f32,
+    /// 距下次迸发闪电链的时间（仅电弧激光）
+    pub arc_timer: f32,
+}
+
+/// 激光伤害结算间隔（秒）
+pub const LASER_TICK_INTERVAL: f32 = 0.1;
+/// 电弧激光迸发闪电链的间隔（秒）
+pub const ARC_LASER_INTERVAL: f32 = 0.6;
+/// 电弧激光迸发的闪电链按该等级结算（跳跃次数、范围、伤害）
+pub const ARC_LASER_LIGHTNING_LEVEL: u32 = 2;
+/// 激光达到该等级后不再被阻挡，贯穿整个屏幕
+pub const LASER_PIERCE_LEVEL: u32 = 5;
+/// 光束起点相对战机中心的偏移
+pub const LASER_MUZZLE_OFFSET: f32 = 30.0;
+
+impl LaserBeam {
+    /// 光束宽度：lv1: 8, lv8: 22
+    pub fn width_for_level(level: u32) -> f32 {
+        6.0 + level as f32 * 2.0
+    }
+
+    /// 每次结算的伤害（每秒结算 1 / LASER_TICK_INTERVAL 次）
+    pub fn tick_damage(&self) -> i32 {
+        let damage = 1 + self.level as i32;
+        if self.evolved {
+            damage * 2
+        } else {
+            damage
+        }
+    }
+
+    /// 是否贯穿所有目标
+    pub fn pierces(&self) -> bool {
+        self.evolved || self.level >= LASER_PIERCE_LEVEL
+    }
+
+    /// 伤害来源的武器类型（进化后为弧光激光）
+    pub fn weapon_type(&self) -> WeaponType {
+        if self.evolved {
+            WeaponType::ArcLaser
+        } else {
+            WeaponType::Laser
+        }
+    }
 }
 
 /// 自导导弹
@@ -295,6 +458,66 @@ pub struct BeamWave {
     pub width: f32,
 }
 
+/// 回旋刃：飞行 `range` 距离后折返飞回战机
+#[derive(Component)]
+pub struct BoomerangBlade {
+    pub range: f32,
+    pub traveled: f32,
+    pub speed: f32,
+    pub returning: bool,
+}
+
+/// 感应雷：布设后经过 `arm_timer` 秒激活，敌人进入 `trigger_radius` 时爆炸
+#[derive(Component)]
+pub struct ProximityMine {
+    pub arm_timer: f32,
+    pub trigger_radius: f32,
+    pub blast_radius: f32,
+}
+
+/// 火焰：飞行中逐渐膨胀
+#[derive(Component)]
+pub struct FlamePuff {
+    pub max_lifetime: f32,
+}
+
+/// 点燃：带有该组件的伤害来源命中时使目标燃烧（火焰、炎狱漩涡）
+#[derive(Component)]
+pub struct Ignite {
+    pub burn_damage: i32,
+    pub burn_duration: f32,
+}
+
+/// 燃烧（挂在敌人身上）：每 BURN_TICK_INTERVAL 秒受到 `damage` 点伤害
+#[derive(Component)]
+pub struct Burning {
+    pub damage: i32,
+    pub remaining: f32,
+    pub tick_timer: f32,
+}
+
+/// 燃烧伤害间隔（秒）
+pub const BURN_TICK_INTERVAL: f32 = 0.5;
+
+/// 引力井：先向前飞行 `travel` 秒，随后停下并持续 `duration` 秒，
+/// 把 `radius` 内的敌人拉向中心并周期性造成伤害，结束时坍缩爆发
+#[derive(Component)]
+pub struct GravityWellOrb {
+    pub travel: f32,
+    pub duration: f32,
+    pub radius: f32,
+    /// 边缘处的拉力（像素/秒），越靠近中心越强
+    pub pull: f32,
+    pub tick_timer: f32,
+    /// 坍缩时的伤害
+    pub collapse_damage: i32,
+}
+
+/// 引力井伤害间隔（秒）
+pub const GRAVITY_WELL_TICK_INTERVAL: f32 = 0.25;
+/// 引力井蓝图外环半径（按实际半径缩放）
+pub const GRAVITY_WELL_BLUEPRINT_RADIUS: f32 = 80.0;
+
 /// 导弹爆炸特效：大量小三角碎片（不参与碰撞）
 pub fn spawn_rocket_explosion_particles(
     commands: &mut Commands,
@@ -371,6 +594,36 @@ pub fn spawn_hit_sparks(commands: &mut Commands, position: Vec3) {
     commands.entity(entity).insert(EffectLifetime { remaining: 0.14 });
 }
 
+/// 擦弹火花：比命中火花更短更淡的青白色线条
+pub fn spawn_graze_spark(commands: &mut Commands, position: Vec3) {
+    let mut rng = rand::rng();
+    let count = rng.random_range(3..=4);
+    let mut shapes = Vec::with_capacity(count);
+
+    for _ in 0..count {
+        let angle = rng.random_range(0.0..std::f32::consts::TAU);
+        let len = rng.random_range(5.0..9.0);
+        shapes.push(GeometryShape::Line {
+            start: Vec2D::ZERO,
+            end: Vec2D::new(angle.cos() * len, angle.sin() * len),
+            color: ShapeColor::new(0.6, 0.95, 1.0, 0.6),
+            stroke_width: 1.0,
+        });
+    }
+
+    let blueprint = GeometryBlueprint {
+        name: "graze_spark".to_string(),
+        shapes,
+        collision: CollisionShape::Circle { radius: 0.0 },
+        scale: 1.0,
+    };
+
+    let entity = spawn_geometry_entity(commands, &blueprint, position + Vec3::new(0.0, 0.0, 60.0));
+    commands
+        .entity(entity)
+        .insert(EffectLifetime { remaining: 0.10 });
+}
+
 /// Boss 受击闪光：低频白闪，透明度很低
 pub fn spawn_boss_hit_flash(commands: &mut Commands, position: Vec3) {
     let blueprint = GeometryBlueprint {
@@ -406,8 +659,15 @@ pub struct LightningCast {
 // ========== 生成武器子弹 ==========
 
 /// 生成霰弹枪子弹
-pub fn spawn_shotgun_pellets(commands: &mut Commands, position: Vec3, level: u32, speed: f32) {
+pub fn spawn_shotgun_pellets(
+    commands: &mut Commands,
+    position: Vec3,
+    level: u32,
+    speed: f32,
+    stats: &StatModifiers,
+) {
     // 霰弹枪：符合设定（lv1:2，lv5:10），并且角度更集中
+    let speed = stats.scale_speed(speed);
     let pellet_count = 2 + (level - 1) * 2; // lv1: 2, lv5: 10
     let spread_angle = PI / 18.0; // 总扩散约 20°（更像“散射”而非“扇形扫射”）
 
@@ -437,15 +697,16 @@ pub fn spawn_shotgun_pellets(commands: &mut Commands, position: Vec3, level: u32
         commands.entity(entity).insert((
             WeaponBullet {
                 weapon_type: WeaponType::Shotgun,
-                damage: 1,
+                damage: stats.scale_damage(1),
                 velocity,
                 lifetime: 1.6,
             },
             ShotgunPellet {
                 spread_angle: angle,
             },
-            Collider::new(blueprint.collision.clone(), CollisionLayer::PlayerBullet)
+            Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
                 .with_mask(CollisionMask::player_bullet_mask()),
+            SweptCollider::default(),
         ));
     }
 }
@@ -457,9 +718,10 @@ pub fn spawn_rocket(
     level: u32,
     target: Option<Entity>,
     speed: f32,
+    stats: &StatModifiers,
 ) {
     let rocket_count = level; // lv1: 1, lv5: 5
-    let base_speed = speed * (1.0 + 0.1 * (level - 1) as f32);
+    let base_speed = stats.scale_speed(speed) * (1.0 + 0.1 * (level - 1) as f32);
 
     for i in 0..rocket_count {
         let offset_x = if rocket_count > 1 {
@@ -478,7 +740,7 @@ pub fn spawn_rocket(
         commands.entity(entity).insert((
             WeaponBullet {
                 weapon_type: WeaponType::Rocket,
-                damage: 2 + level as i32,
+                damage: stats.scale_damage(2 + level as i32),
                 velocity,
                 lifetime: 5.0,
             },
@@ -488,83 +750,98 @@ pub fn spawn_rocket(
                 speed: base_speed,
                 explosion_radius: 30.0 + 5.0 * level as f32,
             },
-            Collider::new(blueprint.collision.clone(), CollisionLayer::PlayerBullet)
+            Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
                 .with_mask(CollisionMask::player_bullet_mask()),
         ));
     }
 }
 
-/// 生成激光
-pub fn spawn_laser(commands: &mut Commands, position: Vec3, level: u32) {
-    // 激光（L）：发射可移动的“长条”穿透弹，而不是瞬间删除的光束
-    let laser_count = 1 + (level - 1) / 2; // lv1: 1, lv3: 2, lv5: 3
-    let spacing = 25.0;
-    // 视觉上更短，减少遮挡
-    let length = 150.0;
-    // 视觉上更细，避免遮挡视线；碰撞宽度也同步收窄
-    let width = 4.0 + level as f32 * 0.7; // lv1: 4.7, lv5: 7.5
-    let speed = 600.0; // 加快速度
+/// 生成激光光束（长度为 1，由 update_laser_beam 每帧按检测结果拉伸）
+/// `evolved` 为 true 时生成更宽的蓝白色电弧激光
+pub fn spawn_laser(commands: &mut Commands, position: Vec3, level: u32, evolved: bool) -> Entity {
+    let (width, (r, g, b)) = if evolved {
+        (LaserBeam::width_for_level(level) * 1.4, (0.4, 0.75, 1.0))
+    } else {
+        (LaserBeam::width_for_level(level), (0.3, 1.0, 0.5))
+    };
+    // 三层：外层淡光 + 中层光束 + 内层亮芯
+    let band = |w: f32, color: ShapeColor| GeometryShape::Polygon {
+        vertices: vec![
+            Vec2D::new(-w * 0.5, 0.0),
+            Vec2D::new(w * 0.5, 0.0),
+            Vec2D::new(w * 0.5, 1.0),
+            Vec2D::new(-w * 0.5, 1.0),
+        ],
+        color,
+        fill: true,
+        stroke_width: 1.0,
+    };
+    let blueprint = GeometryBlueprint {
+        name: "laser_beam".to_string(),
+        shapes: vec![
+            band(width * 1.6, ShapeColor::new(r, g, b, 0.10)),
+            band(width, ShapeColor::new(r, g, b, 0.30)),
+            band(
+                (width * 0.35).max(2.0),
+                ShapeColor::new(0.9, 1.0, 0.95, 0.75),
+            ),
+        ],
+        collision: CollisionShape::Circle { radius: 0.0 },
+        scale: 1.0,
+    };
 
-    for i in 0..laser_count {
-        let offset_x = if laser_count > 1 {
-            ((i as f32) - (laser_count as f32 - 1.0) / 2.0) * spacing
-        } else {
-            0.0
-        };
+    let entity = spawn_geometry_entity(commands, &blueprint, position);
+    commands.entity(entity).insert((
+        LaserBeam {
+            level,
+            evolved,
+            width,
+            length: 0.0,
+            hits: Vec::new(),
+            tick_timer: 0.0,
+            arc_timer: ARC_LASER_INTERVAL,
+        },
+        Visibility::Hidden,
+    ));
+    entity
+}
 
-        // 两层：外层淡光（更透明）+ 内层亮芯（更细）
-        let core_width = (width * 0.40).max(1.8);
-        let blueprint = GeometryBlueprint {
-            name: "laser".to_string(),
-            shapes: vec![
-                GeometryShape::Polygon {
-                    vertices: vec![
-                        Vec2D::new(-width * 0.5, -length * 0.5),
-                        Vec2D::new(width * 0.5, -length * 0.5),
-                        Vec2D::new(width * 0.5, length * 0.5),
-                        Vec2D::new(-width * 0.5, length * 0.5),
-                    ],
-                    color: ShapeColor::new(0.25, 1.0, 0.45, 0.12),
-                    fill: true,
-                    stroke_width: 1.0,
-                },
-                GeometryShape::Polygon {
-                    vertices: vec![
-                        Vec2D::new(-core_width * 0.5, -length * 0.5),
-                        Vec2D::new(core_width * 0.5, -length * 0.5),
-                        Vec2D::new(core_width * 0.5, length * 0.5),
-                        Vec2D::new(-core_width * 0.5, length * 0.5),
-                    ],
-                    color: ShapeColor::new(0.75, 1.0, 0.85, 0.18),
-                    fill: true,
-                    stroke_width: 1.0,
-                },
-            ],
-            collision: CollisionShape::Rectangle {
-                width,
-                height: length,
-            },
-            scale: 1.0,
-        };
+/// 激光命中火花：绿白色短线，向光束两侧与下方飞溅
+pub fn spawn_laser_sparks(commands: &mut Commands, position: Vec3, width: f32) {
+    let mut rng = rand::rng();
+    let count = rng.random_range(4..=6);
+    let mut shapes = Vec::with_capacity(count + 1);
 
-        let pos = position + Vec3::new(offset_x, 40.0 + length * 0.5, 0.0);
-        let entity = spawn_geometry_entity(commands, &blueprint, pos);
-        commands.entity(entity).insert((
-            WeaponBullet {
-                weapon_type: WeaponType::Laser,
-                damage: 2 + level as i32, // 提升伤害
-                velocity: Vec2::new(0.0, speed),
-                lifetime: 2.0,
-            },
-            Pierce {
-                remaining: u32::MAX,
-            },
-            HitList::default(),
-            LaserBeam { width, length },
-            Collider::new(blueprint.collision.clone(), CollisionLayer::PlayerBullet)
-                .with_mask(CollisionMask::player_bullet_mask()),
-        ));
+    for _ in 0..count {
+        // 以正下方为中心的半圆内飞溅
+        let angle = -PI / 2.0 + rng.random_range(-1.4..1.4);
+        let len = rng.random_range(8.0..14.0) + width * 0.4;
+        shapes.push(GeometryShape::Line {
+            start: Vec2D::ZERO,
+            end: Vec2D::new(angle.cos() * len, angle.sin() * len),
+            color: ShapeColor::new(0.7, 1.0, 0.75, 0.6),
+            stroke_width: 1.3,
+        });
     }
+    shapes.push(GeometryShape::Circle {
+        center: Vec2D::ZERO,
+        radius: width * 0.6,
+        color: ShapeColor::new(0.85, 1.0, 0.9, 0.35),
+        fill: true,
+        stroke_width: 1.0,
+    });
+
+    let blueprint = GeometryBlueprint {
+        name: "laser_sparks".to_string(),
+        shapes,
+        collision: CollisionShape::Circle { radius: 0.0 },
+        scale: 1.0,
+    };
+
+    let entity = spawn_geometry_entity(commands, &blueprint, position + Vec3::new(0.0, 0.0, 60.0));
+    commands
+        .entity(entity)
+        .insert(EffectLifetime { remaining: 0.1 });
 }
 
 /// 生成自导导弹
@@ -574,8 +851,10 @@ pub fn spawn_homing_missile(
     level: u32,
     target: Option<Entity>,
     speed: f32,
+    stats: &StatModifiers,
 ) {
     let missile_count = level; // lv1: 1, lv5: 5
+    let speed = stats.scale_speed(speed);
 
     for i in 0..missile_count {
         let offset_x = if missile_count > 1 {
@@ -607,7 +886,7 @@ pub fn spawn_homing_missile(
         commands.entity(entity).insert((
             WeaponBullet {
                 weapon_type: WeaponType::Homing,
-                damage: 2,
+                damage: stats.scale_damage(2),
                 velocity,
                 lifetime: 4.0,
             },
@@ -617,7 +896,76 @@ pub fn spawn_homing_missile(
                 max_lifetime: 4.0,
                 speed,
             },
-            Collider::new(blueprint.collision.clone(), CollisionLayer::PlayerBullet)
+            Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
+                .with_mask(CollisionMask::player_bullet_mask()),
+        ));
+    }
+}
+
+/// 生成蜂群导弹：扇形放出一群追踪导弹，命中或超时时爆炸
+pub fn spawn_swarm_missiles(
+    commands: &mut Commands,
+    position: Vec3,
+    target: Option<Entity>,
+    speed: f32,
+    stats: &StatModifiers,
+) {
+    let missile_count = 8;
+    let spread_angle = PI * 0.75;
+    let speed = stats.scale_speed(speed) * 0.9;
+
+    for i in 0..missile_count {
+        let t = (i as f32) / (missile_count as f32 - 1.0);
+        let angle = PI / 2.0 + spread_angle * (t - 0.5);
+        let velocity = Vec2::new(angle.cos(), angle.sin()) * speed;
+
+        let blueprint = GeometryBlueprint {
+            name: "swarm_missile".to_string(),
+            shapes: vec![
+                GeometryShape::Polygon {
+                    vertices: vec![
+                        Vec2D::new(0.0, 7.0),
+                        Vec2D::new(-3.5, -4.0),
+                        Vec2D::new(3.5, -4.0),
+                    ],
+                    color: ShapeColor::new(1.0, 0.6, 0.25, 0.95),
+                    fill: true,
+                    stroke_width: 1.0,
+                },
+                GeometryShape::Circle {
+                    center: Vec2D::new(0.0, -5.0),
+                    radius: 2.0,
+                    color: ShapeColor::new(1.0, 0.9, 0.4, 0.8),
+                    fill: true,
+                    stroke_width: 1.0,
+                },
+            ],
+            collision: CollisionShape::Circle { radius: 5.0 },
+            scale: 1.0,
+        };
+
+        let entity = spawn_geometry_entity(commands, &blueprint, position);
+        commands.entity(entity).insert((
+            WeaponBullet {
+                weapon_type: WeaponType::SwarmMissiles,
+                damage: stats.scale_damage(6),
+                velocity,
+                lifetime: 4.0,
+            },
+            // 移动与爆炸沿用导弹逻辑，转向沿用自导导弹逻辑
+            RocketBullet {
+                target,
+                initialized: true,
+                speed,
+                explosion_radius: 45.0,
+            },
+            HomingMissile {
+                target,
+                turn_rate: 5.0,
+                max_lifetime: 4.0,
+                speed,
+            },
+            Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
                 .with_mask(CollisionMask::player_bullet_mask()),
         ));
     }
@@ -629,6 +977,7 @@ pub fn spawn_lightning(
     position: Vec3,
     level: u32,
     _first_target: Option<Entity>,
+    stats: &StatModifiers,
 ) {
     let entity = commands
         .spawn((
@@ -642,7 +991,7 @@ pub fn spawn_lightning(
     commands.entity(entity).insert(LightningCast {
         jumps: 3 + level, // lv1:4, lv5:8（包含第一跳）
         range: (280.0 + 50.0 * level as f32).min(600.0),
-        damage: 3 + level as i32, // 提升伤害
+        damage: stats.scale_damage(3 + level as i32), // 提升伤害
     });
 }
 
@@ -685,12 +1034,9 @@ pub fn spawn_aura_orbs(commands: &mut Commands, player_entity: Entity, level: u3
                 orbit_radius,
             },
             AuraOwner(player_entity),
-            Collider::new(blueprint.collision.clone(), CollisionLayer::PlayerBullet).with_mask(
-                CollisionMask {
-                    // 允许与敌人子弹碰撞，实现“抵消子弹”
-                    enemy_bullet: true,
-                    ..CollisionMask::player_bullet_mask()
-                },
+            Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET).with_mask(
+                // 允许与敌人子弹碰撞，实现“抵消子弹”
+                CollisionMask::player_bullet_mask().with(CollisionLayer::ENEMY_BULLET),
             ),
         ));
     }
@@ -701,7 +1047,13 @@ pub fn spawn_aura_orbs(commands: &mut Commands, player_entity: Entity, level: u3
 pub struct AuraOwner(pub Entity);
 
 /// 生成光柱
-pub fn spawn_beam_wave(commands: &mut Commands, config: &GameConfig, position: Vec3, level: u32) {
+pub fn spawn_beam_wave(
+    commands: &mut Commands,
+    config: &GameConfig,
+    position: Vec3,
+    level: u32,
+    stats: &StatModifiers,
+) {
     // 能量波（C）：更像格斗游戏“气动波”
     // - 颜色更克制（偏蓝白半透明），避免“彩虹”刺眼
     // - 两端更窄（taper），中间更厚
@@ -710,7 +1062,7 @@ pub fn spawn_beam_wave(commands: &mut Commands, config: &GameConfig, position: V
     // 目标宽度：半屏幕（≈ window_width / 2），半圆宽度为 2*radius，因此 radius = window_width / 4。
     let radius = config.window_width * 0.25;
     let base_thickness = 10.0 + 1.2 * level as f32; // 中段厚度，整体更细
-    let speed = config.bullet_speed * 0.9;
+    let speed = stats.scale_speed(config.bullet_speed) * 0.9;
 
     let segments = 44;
     let mut outer: Vec<Vec2D> = Vec::with_capacity(segments + 1);
@@ -766,8 +1118,11 @@ pub fn spawn_beam_wave(commands: &mut Commands, config: &GameConfig, position: V
                 stroke_width: 2.0,
             },
         ],
-        collision: CollisionShape::Circle {
-            radius: radius + base_thickness * 0.6,
+        // 横跨半圆弧的胶囊：覆盖弧顶与两端，不再包含弧下方的整块圆盘
+        collision: CollisionShape::Capsule {
+            start: Vec2D::new(-radius * 0.75, radius * 0.45),
+            end: Vec2D::new(radius * 0.75, radius * 0.45),
+            radius: radius * 0.55 + base_thickness * 0.5,
         },
         scale: 1.0,
     };
@@ -776,7 +1131,7 @@ pub fn spawn_beam_wave(commands: &mut Commands, config: &GameConfig, position: V
     commands.entity(entity).insert((
         WeaponBullet {
             weapon_type: WeaponType::Beam,
-            damage: 4 + level as i32 * 2, // 伤害翻倍
+            damage: stats.scale_damage(4 + level as i32 * 2), // 伤害翻倍
             velocity: Vec2::new(0.0, speed),
             lifetime: 2.0,
         },
@@ -788,11 +1143,205 @@ pub fn spawn_beam_wave(commands: &mut Commands, config: &GameConfig, position: V
             progress: 0.0,
             width: radius,
         },
-        Collider::new(blueprint.collision.clone(), CollisionLayer::PlayerBullet)
+        Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
             .with_mask(CollisionMask::player_bullet_mask()),
     ));
 }
 
+/// 生成回旋刃
+pub fn spawn_boomerang_blades(
+    commands: &mut Commands,
+    position: Vec3,
+    level: u32,
+    speed: f32,
+    stats: &StatModifiers,
+) {
+    let blade_count = 1 + (level - 1) / 2; // lv1: 1, lv3: 2, lv5: 3, lv7: 4
+    let spread_angle = PI / 5.0;
+    let range = 180.0 + 15.0 * level as f32;
+    let speed = stats.scale_speed(speed) * 0.8;
+
+    for i in 0..blade_count {
+        let t = if blade_count <= 1 {
+            0.5
+        } else {
+            (i as f32) / (blade_count as f32 - 1.0)
+        };
+        let angle = PI / 2.0 + spread_angle * (t - 0.5);
+        let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
+
+        let blueprint = GeometryBlueprint::boomerang_blade();
+        let pos = position + Vec3::new(0.0, 20.0, 0.0);
+        let entity = spawn_geometry_entity(commands, &blueprint, pos);
+        commands.entity(entity).insert((
+            WeaponBullet {
+                weapon_type: WeaponType::Boomerang,
+                damage: stats.scale_damage(2 + level as i32),
+                velocity,
+                lifetime: 4.0,
+            },
+            Pierce {
+                remaining: u32::MAX,
+            },
+            HitList::default(),
+            BoomerangBlade {
+                range,
+                traveled: 0.0,
+                speed,
+                returning: false,
+            },
+            Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
+                .with_mask(CollisionMask::player_bullet_mask()),
+        ));
+    }
+}
+
+/// 生成感应雷：布置在战机后方，随画面缓慢下移
+pub fn spawn_proximity_mines(
+    commands: &mut Commands,
+    position: Vec3,
+    level: u32,
+    stats: &StatModifiers,
+) {
+    let mine_count = 1 + level / 4; // lv1: 1, lv4: 2, lv8: 3
+    let spacing = 24.0;
+
+    for i in 0..mine_count {
+        let offset_x = ((i as f32) - (mine_count as f32 - 1.0) / 2.0) * spacing;
+        let blueprint = GeometryBlueprint::proximity_mine();
+        let pos = position + Vec3::new(offset_x, -30.0, -1.0);
+        let entity = spawn_geometry_entity(commands, &blueprint, pos);
+        commands.entity(entity).insert((
+            WeaponBullet {
+                weapon_type: WeaponType::Mine,
+                damage: stats.scale_damage(4 + 2 * level as i32),
+                velocity: Vec2::new(0.0, -30.0),
+                lifetime: 8.0,
+            },
+            ProximityMine {
+                arm_timer: 0.5,
+                trigger_radius: 36.0 + 3.0 * level as f32,
+                blast_radius: 50.0 + 6.0 * level as f32,
+            },
+        ));
+    }
+}
+
+/// 生成火焰：在机头前方锥形范围内随机喷出
+pub fn spawn_flame_burst(
+    commands: &mut Commands,
+    position: Vec3,
+    level: u32,
+    stats: &StatModifiers,
+) {
+    let mut rng = rand::rng();
+    let puff_count = 1 + level / 3; // lv1: 1, lv3: 2, lv6: 3
+    let half_cone = 0.3 + 0.02 * level as f32;
+    let speed = stats.scale_speed(320.0);
+    let lifetime = 0.28 + 0.02 * level as f32; // 射程约 100 ~ 140
+
+    for _ in 0..puff_count {
+        let angle = PI / 2.0 + rng.random_range(-half_cone..half_cone);
+        let velocity = Vec2::new(angle.cos(), angle.sin()) * speed * rng.random_range(0.85..1.0);
+
+        let blueprint = GeometryBlueprint::flame_puff();
+        let pos = position + Vec3::new(0.0, 28.0, 1.0);
+        let entity = spawn_geometry_entity(commands, &blueprint, pos);
+        commands.entity(entity).insert((
+            WeaponBullet {
+                weapon_type: WeaponType::Flamethrower,
+                damage: stats.scale_damage(1),
+                velocity,
+                lifetime,
+            },
+            Pierce {
+                remaining: u32::MAX,
+            },
+            HitList::default(),
+            FlamePuff {
+                max_lifetime: lifetime,
+            },
+            Ignite {
+                burn_damage: stats.scale_damage(1 + level as i32 / 3),
+                burn_duration: 1.5 + 0.25 * level as f32,
+            },
+            Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
+                .with_mask(CollisionMask::player_bullet_mask()),
+        ));
+    }
+}
+
+/// 生成引力井
+pub fn spawn_gravity_well(
+    commands: &mut Commands,
+    position: Vec3,
+    level: u32,
+    stats: &StatModifiers,
+) {
+    let blueprint = GeometryBlueprint::gravity_well();
+    spawn_well(
+        commands,
+        position,
+        WeaponType::GravityWell,
+        level,
+        blueprint,
+        stats,
+    );
+}
+
+/// 生成炎狱漩涡：超出满级的引力井，并点燃范围内的敌人
+pub fn spawn_inferno_vortex(commands: &mut Commands, position: Vec3, stats: &StatModifiers) {
+    let blueprint = GeometryBlueprint::inferno_vortex();
+    let level = MAX_WEAPON_LEVEL + 2;
+    let entity = spawn_well(
+        commands,
+        position,
+        WeaponType::InfernoVortex,
+        level,
+        blueprint,
+        stats,
+    );
+    commands.entity(entity).insert(Ignite {
+        burn_damage: stats.scale_damage(4),
+        burn_duration: 3.0,
+    });
+}
+
+fn spawn_well(
+    commands: &mut Commands,
+    position: Vec3,
+    weapon_type: WeaponType,
+    level: u32,
+    mut blueprint: GeometryBlueprint,
+    stats: &StatModifiers,
+) -> Entity {
+    let travel = 0.45;
+    let duration = 2.0 + 0.2 * level as f32;
+    let radius = 70.0 + 8.0 * level as f32;
+
+    // 飞行时缩小，停下后展开到实际半径
+    blueprint.scale = radius / GRAVITY_WELL_BLUEPRINT_RADIUS * 0.3;
+    let entity = spawn_geometry_entity(commands, &blueprint, position + Vec3::new(0.0, 40.0, -2.0));
+    commands.entity(entity).insert((
+        WeaponBullet {
+            weapon_type,
+            damage: stats.scale_damage(1 + level as i32 / 2),
+            velocity: Vec2::new(0.0, stats.scale_speed(280.0)),
+            // 由 GravityWellOrb 控制结束时机，这里只做兜底
+            lifetime: travel + duration + 1.0,
+        },
+        GravityWellOrb {
+            travel,
+            duration,
+            radius,
+            pull: 90.0 + 15.0 * level as f32,
+            tick_timer: 0.0,
+            collapse_damage: stats.scale_damage(4 + 2 * level as i32),
+        },
+    ));
+    entity
+}
+
 /// 生成默认子弹（小圆点）
 pub fn spawn_default_bullet(commands: &mut Commands, position: Vec3, speed: f32) {
     let blueprint = GeometryBlueprint::default_bullet();
@@ -805,7 +1354,80 @@ pub fn spawn_default_bullet(commands: &mut Commands, position: Vec3, speed: f32)
             velocity: Vec2::new(0.0, speed),
             lifetime: 3.0,
         },
-        Collider::new(blueprint.collision.clone(), CollisionLayer::PlayerBullet)
+        Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
+            .with_mask(CollisionMask::player_bullet_mask()),
+        SweptCollider::default(),
+    ));
+}
+
+/// 蓄力达到该比例才会释放蓄力弹（否则视为普通点射）
+pub const MIN_CHARGE_RATIO: f32 = 0.25;
+/// 蓄满所需时间（秒）
+pub const MAX_CHARGE_TIME: f32 = 1.2;
+
+/// 生成蓄力弹：伤害、体积、穿透次数随蓄力比例（0~1）增长
+pub fn spawn_charged_shot(
+    commands: &mut Commands,
+    position: Vec3,
+    ratio: f32,
+    speed: f32,
+    stats: &StatModifiers,
+) {
+    let ratio = ratio.clamp(0.0, 1.0);
+    let speed = stats.scale_speed(speed);
+    let radius = 7.0 + 11.0 * ratio;
+    let length = radius * 2.6;
+
+    let blueprint = GeometryBlueprint {
+        name: "charged_shot".to_string(),
+        shapes: vec![
+            // 外层光晕
+            GeometryShape::Circle {
+                center: Vec2D::ZERO,
+                radius: radius * 1.35,
+                color: ShapeColor::new(0.3, 0.85, 1.0, 0.18),
+                fill: true,
+                stroke_width: 1.0,
+            },
+            // 菱形弹体
+            GeometryShape::Polygon {
+                vertices: vec![
+                    Vec2D::new(0.0, length * 0.5),
+                    Vec2D::new(radius * 0.7, 0.0),
+                    Vec2D::new(0.0, -length * 0.5),
+                    Vec2D::new(-radius * 0.7, 0.0),
+                ],
+                color: ShapeColor::new(0.55, 0.95, 1.0, 0.85),
+                fill: true,
+                stroke_width: 1.0,
+            },
+            // 亮芯
+            GeometryShape::Circle {
+                center: Vec2D::ZERO,
+                radius: radius * 0.35,
+                color: ShapeColor::new(1.0, 1.0, 1.0, 0.95),
+                fill: true,
+                stroke_width: 1.0,
+            },
+        ],
+        collision: CollisionShape::Circle { radius },
+        scale: 1.0,
+    };
+
+    let entity = spawn_geometry_entity(commands, &blueprint, position);
+    commands.entity(entity).insert((
+        WeaponBullet {
+            weapon_type: WeaponType::Shotgun, // 与默认子弹同源
+            damage: stats.scale_damage(3 + (17.0 * ratio).round() as i32), // 3 ~ 20
+            velocity: Vec2::new(0.0, speed * (1.0 + 0.3 * ratio)),
+            lifetime: 3.0,
+        },
+        Pierce {
+            remaining: 2 + (4.0 * ratio).round() as u32, // 2 ~ 6
+        },
+        HitList::default(),
+        Collider::new(blueprint.collision.clone(), CollisionLayer::PLAYER_BULLET)
             .with_mask(CollisionMask::player_bullet_mask()),
+        SweptCollider::default(),
     ));
 }
@@ -1,9 +1,12 @@
 //! 碰撞检测系统
 
+use bevy::platform::collections::{HashMap, HashSet};
 use bevy::prelude::*;
 
-use crate::geometry::CollisionShape;
+use crate::geometry::{CollisionShape, Vec2D};
 
+use super::narrowphase::{convex_decompose, WorldShape};
+use super::spatial_hash::SpatialHash;
 use super::states::{not_upgrading, GameState};
 
 /// 碰撞系统插件
@@ -11,119 +14,356 @@ pub struct CollisionPlugin;
 
 impl Plugin for CollisionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<CollisionEvent>().add_systems(
-            Update,
-            detect_collisions
-                .run_if(in_state(GameState::Playing))
-                .run_if(not_upgrading),
-        );
+        app.init_resource::<CollisionLayerRegistry>()
+            .add_message::<CollisionEvent>()
+            .add_message::<GrazeEvent>()
+            .add_systems(
+                Update,
+                (
+                    detect_collisions,
+                    detect_grazes.after(detect_collisions),
+                    record_swept_positions.after(detect_collisions),
+                )
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not_upgrading),
+            );
     }
 }
 
 /// 碰撞器组件
+///
+/// 多边形形状在构造时预先拆分为凸块并缓存；改变形状请使用 [`Collider::set_shape`]，以便重建缓存。
 #[derive(Component)]
 pub struct Collider {
-    pub shape: CollisionShape,
+    shape: CollisionShape,
     pub layer: CollisionLayer,
     pub mask: CollisionMask,
+    /// 每个子形状的凸分解（局部坐标，不含偏移），非多边形为空
+    convex_parts: Vec<Vec<Vec<Vec2>>>,
 }
 
 impl Collider {
     pub fn new(shape: CollisionShape, layer: CollisionLayer) -> Self {
         Self {
+            convex_parts: decompose_shape(&shape),
             shape,
             layer,
             mask: CollisionMask::default(),
         }
     }
 
+    /// 碰撞形状（局部坐标）
+    pub fn shape(&self) -> &CollisionShape {
+        &self.shape
+    }
+
+    /// 替换碰撞形状并重建凸分解缓存
+    pub fn set_shape(&mut self, shape: CollisionShape) {
+        self.convex_parts = decompose_shape(&shape);
+        self.shape = shape;
+    }
+
     pub fn with_mask(mut self, mask: CollisionMask) -> Self {
         self.mask = mask;
         self
     }
+
+    /// 是否为复合碰撞箱
+    pub fn is_compound(&self) -> bool {
+        matches!(self.shape, CollisionShape::Compound { .. })
+    }
+
+    /// 外接圆半径（局部坐标，未乘实体缩放）
+    pub fn bounding_radius(&self) -> f32 {
+        shape_bounding_radius(&self.shape)
+    }
+
+    /// 光束首次碰到该碰撞体时距光束起点的距离，按精确形状判定，碰不到返回 None
+    ///
+    /// 光束从 `origin` 沿单位向量 `direction` 射出，半宽 `half_width`，最长 `max_length`。
+    /// 对光束长度二分查找，每次用分离轴检测光束矩形与各子形状是否相交。
+    pub fn beam_entry(
+        &self,
+        transform: &Transform,
+        origin: Vec2,
+        direction: Vec2,
+        half_width: f32,
+        max_length: f32,
+    ) -> Option<f32> {
+        const STEPS: u32 = 16;
+
+        let shapes = world_shapes(ColliderPose::from_transform(transform), self);
+        let side = direction.perp() * half_width;
+        let hits = |length: f32| {
+            let end = origin + direction * length;
+            let beam = WorldShape::Convex(vec![vec![
+                origin + side,
+                origin - side,
+                end - side,
+                end + side,
+            ]]);
+            shapes.iter().any(|shape| shape.intersects(&beam))
+        };
+        if max_length <= 0.0 || !hits(max_length) {
+            return None;
+        }
+        let (mut near, mut far) = (0.0, max_length);
+        for _ in 0..STEPS {
+            let mid = (near + far) / 2.0;
+            if hits(mid) {
+                far = mid;
+            } else {
+                near = mid;
+            }
+        }
+        Some(far)
+    }
+
+    /// 按索引列出子形状：(偏移, 形状)；简单形状只有一个、偏移为零
+    fn sub_shapes(&self) -> Vec<(Vec2D, &CollisionShape)> {
+        match &self.shape {
+            CollisionShape::Compound { parts } => {
+                parts.iter().map(|p| (p.offset, &p.shape)).collect()
+            }
+            other => vec![(Vec2D::ZERO, other)],
+        }
+    }
+}
+
+/// 按子形状索引拆分凸块（非多边形子形状为空）
+fn decompose_shape(shape: &CollisionShape) -> Vec<Vec<Vec<Vec2>>> {
+    let decompose = |shape: &CollisionShape| match shape {
+        CollisionShape::Polygon { vertices } => convex_decompose(vertices),
+        _ => Vec::new(),
+    };
+    match shape {
+        CollisionShape::Compound { parts } => parts.iter().map(|p| decompose(&p.shape)).collect(),
+        other => vec![decompose(other)],
+    }
 }
 
-/// 碰撞层
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum CollisionLayer {
-    Player,
-    PlayerBullet,
-    Enemy,
-    EnemyBullet,
-    PowerUp,
+/// 敌方弹体速度（像素/秒）达到该值时启用扫掠检测
+pub const SWEPT_SPEED_THRESHOLD: f32 = 250.0;
+
+/// 连续碰撞检测（可选）：检测上一帧位置到当前位置扫过的整个区域
+///
+/// 用于高速小型弹体，避免低帧率时一帧内直接穿过目标。扫掠只考虑平移，不考虑期间的旋转。
+#[derive(Component, Debug, Default)]
+pub struct SweptCollider {
+    /// 上一次碰撞检测时的位置（刚生成时为空，不做扫掠）
+    previous: Option<Vec2>,
 }
 
-/// 碰撞掩码
-#[derive(Debug, Clone, Copy)]
-pub struct CollisionMask {
-    pub player: bool,
-    pub player_bullet: bool,
-    pub enemy: bool,
-    pub enemy_bullet: bool,
-    pub power_up: bool,
+/// 弱点：按复合碰撞箱的子形状索引给出伤害倍率（未列出的子形状为 1.0）
+#[derive(Component, Debug, Clone)]
+pub struct WeakPoints {
+    pub multipliers: Vec<f32>,
 }
 
+impl WeakPoints {
+    pub fn new(multipliers: Vec<f32>) -> Self {
+        Self { multipliers }
+    }
+
+    /// 命中子形状对应的伤害倍率
+    pub fn multiplier(&self, sub_shape: Option<usize>) -> f32 {
+        sub_shape
+            .and_then(|i| self.multipliers.get(i).copied())
+            .unwrap_or(1.0)
+    }
+}
+
+/// 碰撞层：单个位标志
+///
+/// 内置五个层占用低 5 位，其余位留给游戏通过 [`CollisionLayerRegistry`] 注册的自定义层
+/// （地形、危险区、吸附区、反弹子弹等）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollisionLayer(u32);
+
+impl CollisionLayer {
+    pub const PLAYER: Self = Self(1 << 0);
+    pub const PLAYER_BULLET: Self = Self(1 << 1);
+    pub const ENEMY: Self = Self(1 << 2);
+    pub const ENEMY_BULLET: Self = Self(1 << 3);
+    pub const POWER_UP: Self = Self(1 << 4);
+
+    /// 内置层（名称, 层），注册表按此预先登记
+    pub const BUILTIN: [(&'static str, Self); 5] = [
+        ("player", Self::PLAYER),
+        ("player_bullet", Self::PLAYER_BULLET),
+        ("enemy", Self::ENEMY),
+        ("enemy_bullet", Self::ENEMY_BULLET),
+        ("power_up", Self::POWER_UP),
+    ];
+
+    /// 可用的层总数
+    pub const MAX_LAYERS: u32 = u32::BITS;
+
+    /// 按位序号构造层（0 ~ 31）
+    pub const fn from_bit(bit: u32) -> Self {
+        assert!(bit < Self::MAX_LAYERS, "碰撞层位序号超出范围");
+        Self(1 << bit)
+    }
+
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+}
+
+impl std::ops::BitOr for CollisionLayer {
+    type Output = CollisionMask;
+
+    fn bitor(self, rhs: Self) -> CollisionMask {
+        CollisionMask(self.0 | rhs.0)
+    }
+}
+
+/// 碰撞掩码：可与之碰撞的层的集合
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollisionMask(u32);
+
 impl Default for CollisionMask {
     fn default() -> Self {
-        Self {
-            player: true,
-            player_bullet: true,
-            enemy: true,
-            enemy_bullet: true,
-            power_up: true,
-        }
+        Self::ALL
+    }
+}
+
+impl From<CollisionLayer> for CollisionMask {
+    fn from(layer: CollisionLayer) -> Self {
+        Self(layer.0)
+    }
+}
+
+impl std::ops::BitOr<CollisionLayer> for CollisionMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: CollisionLayer) -> Self {
+        self.with(rhs)
+    }
+}
+
+impl std::ops::BitOr for CollisionMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
     }
 }
 
 impl CollisionMask {
-    pub fn player_mask() -> Self {
-        Self {
-            player: false,
-            player_bullet: false,
-            enemy: true,
-            enemy_bullet: true,
-            power_up: true,
+    pub const NONE: Self = Self(0);
+    pub const ALL: Self = Self(u32::MAX);
+
+    /// 由若干层组成的掩码
+    pub const fn from_layers(layers: &[CollisionLayer]) -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < layers.len() {
+            bits |= layers[i].0;
+            i += 1;
         }
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// 加入一个层
+    pub const fn with(self, layer: CollisionLayer) -> Self {
+        Self(self.0 | layer.0)
+    }
+
+    /// 移除一个层
+    pub const fn without(self, layer: CollisionLayer) -> Self {
+        Self(self.0 & !layer.0)
+    }
+
+    pub fn player_mask() -> Self {
+        Self::from_layers(&[
+            CollisionLayer::ENEMY,
+            CollisionLayer::ENEMY_BULLET,
+            CollisionLayer::POWER_UP,
+        ])
     }
 
     pub fn player_bullet_mask() -> Self {
-        Self {
-            player: false,
-            player_bullet: false,
-            enemy: true,
-            enemy_bullet: false,
-            power_up: false,
-        }
+        Self::from_layers(&[CollisionLayer::ENEMY])
     }
 
     pub fn enemy_mask() -> Self {
-        Self {
-            player: true,
-            player_bullet: true,
-            enemy: false,
-            enemy_bullet: false,
-            power_up: false,
-        }
+        Self::from_layers(&[CollisionLayer::PLAYER, CollisionLayer::PLAYER_BULLET])
     }
 
     pub fn enemy_bullet_mask() -> Self {
+        Self::from_layers(&[CollisionLayer::PLAYER])
+    }
+
+    pub fn can_collide_with(&self, layer: CollisionLayer) -> bool {
+        self.0 & layer.0 != 0
+    }
+}
+
+/// 碰撞层注册表：按名称分配游戏自定义的碰撞层
+///
+/// 内置层已预先登记；同名重复注册返回同一个层。
+#[derive(Resource, Debug, Clone)]
+pub struct CollisionLayerRegistry {
+    layers: Vec<(String, CollisionLayer)>,
+}
+
+impl Default for CollisionLayerRegistry {
+    fn default() -> Self {
         Self {
-            player: true,
-            player_bullet: false,
-            enemy: false,
-            enemy_bullet: false,
-            power_up: false,
+            layers: CollisionLayer::BUILTIN
+                .iter()
+                .map(|(name, layer)| (name.to_string(), *layer))
+                .collect(),
         }
     }
+}
 
-    pub fn can_collide_with(&self, layer: CollisionLayer) -> bool {
-        match layer {
-            CollisionLayer::Player => self.player,
-            CollisionLayer::PlayerBullet => self.player_bullet,
-            CollisionLayer::Enemy => self.enemy,
-            CollisionLayer::EnemyBullet => self.enemy_bullet,
-            CollisionLayer::PowerUp => self.power_up,
+impl CollisionLayerRegistry {
+    /// 注册（或取回）指定名称的碰撞层
+    ///
+    /// 32 个位全部占用时 panic：层数在开发期即可确定，超出属于配置错误。
+    pub fn register(&mut self, name: &str) -> CollisionLayer {
+        if let Some(layer) = self.get(name) {
+            return layer;
         }
+        let bit = self.layers.len() as u32;
+        assert!(
+            bit < CollisionLayer::MAX_LAYERS,
+            "碰撞层已用尽，无法注册 {}",
+            name
+        );
+        let layer = CollisionLayer::from_bit(bit);
+        self.layers.push((name.to_string(), layer));
+        layer
+    }
+
+    /// 按名称查找碰撞层
+    pub fn get(&self, name: &str) -> Option<CollisionLayer> {
+        self.layers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, layer)| *layer)
+    }
+
+    /// 碰撞层的名称（调试显示用）
+    pub fn name_of(&self, layer: CollisionLayer) -> Option<&str> {
+        self.layers
+            .iter()
+            .find(|(_, l)| *l == layer)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// 由名称列表组成掩码（未注册的名称被忽略）
+    pub fn mask(&self, names: &[&str]) -> CollisionMask {
+        names
+            .iter()
+            .filter_map(|name| self.get(name))
+            .fold(CollisionMask::NONE, CollisionMask::with)
     }
 }
 
@@ -134,61 +374,254 @@ pub struct CollisionEvent {
     pub entity_b: Entity,
     pub layer_a: CollisionLayer,
     pub layer_b: CollisionLayer,
+    /// 复合碰撞箱中被命中的子形状索引（简单形状为 None）
+    pub sub_shape_a: Option<usize>,
+    pub sub_shape_b: Option<usize>,
+}
+
+/// 擦弹判定距离：在双方碰撞箱之外额外扩展的范围
+pub const GRAZE_RADIUS: f32 = 22.0;
+
+/// 擦弹事件：敌方子弹从玩家身边掠过（未命中）
+#[derive(Message)]
+pub struct GrazeEvent {
+    pub player: Entity,
+    pub bullet: Entity,
+    /// 擦弹点（子弹位置）
+    pub position: Vec2,
+}
+
+/// 已擦弹标记：每颗子弹只结算一次擦弹
+#[derive(Component)]
+pub struct Grazed;
+
+/// 正处于擦弹范围内的子弹
+struct GrazeTrack {
+    player: Entity,
+    /// 最后一次在擦弹范围内的位置（作为擦弹点）
+    position: Vec2,
 }
 
 /// 检测碰撞
+///
+/// 宽相位：每帧把碰撞体按外接包围盒放入空间哈希，只对同格且碰撞层匹配的组合做精确检测。
+/// 带 [`SweptCollider`] 的碰撞体，包围盒同时覆盖上一帧位置，精确检测按扫掠区域进行。
 fn detect_collisions(
-    query: Query<(Entity, &Transform, &Collider)>,
+    query: Query<(Entity, &Transform, &Collider, Option<&SweptCollider>)>,
+    mut grid: Local<SpatialHash>,
     mut collision_events: MessageWriter<CollisionEvent>,
 ) {
-    let entities: Vec<_> = query.iter().collect();
-
-    for i in 0..entities.len() {
-        for j in (i + 1)..entities.len() {
-            let (entity_a, transform_a, collider_a) = entities[i];
-            let (entity_b, transform_b, collider_b) = entities[j];
-
-            // 检查碰撞掩码
-            if !collider_a.mask.can_collide_with(collider_b.layer)
-                && !collider_b.mask.can_collide_with(collider_a.layer)
-            {
-                continue;
-            }
+    let entities: Vec<_> = query
+        .iter()
+        .map(|(entity, transform, collider, swept)| {
+            let pose = ColliderPose::from_transform(transform);
+            // 本帧位移（未启用扫掠或刚生成时为零）
+            let delta = swept
+                .and_then(|s| s.previous)
+                .map_or(Vec2::ZERO, |previous| pose.translation() - previous);
+            (entity, pose, collider, delta)
+        })
+        .collect();
+    let bounds: Vec<(Vec2, Vec2)> = entities
+        .iter()
+        .map(|(_, pose, collider, delta)| {
+            // 缩放只会放大随旋转/缩放的形状，取 max(1) 保证包围盒足够保守
+            let r = Vec2::splat(shape_bounding_radius(&collider.shape) * pose.max_scale().max(1.0));
+            let (start, end) = (pose.translation() - *delta, pose.translation());
+            (start.min(end) - r, start.max(end) + r)
+        })
+        .collect();
 
-            // 检测碰撞
-            if check_collision(
-                &transform_a.translation.truncate(),
-                &collider_a.shape,
-                &transform_b.translation.truncate(),
-                &collider_b.shape,
-            ) {
+    grid.clear();
+    for (index, (min, max)) in bounds.iter().enumerate() {
+        grid.insert(index, *min, *max);
+    }
+
+    grid.for_each_pair(
+        &bounds,
+        // 检查碰撞掩码
+        |i, j| {
+            let (_, _, collider_a, _) = entities[i];
+            let (_, _, collider_b, _) = entities[j];
+            collider_a.mask.can_collide_with(collider_b.layer)
+                || collider_b.mask.can_collide_with(collider_a.layer)
+        },
+        |i, j| {
+            let (entity_a, pose_a, collider_a, delta_a) = entities[i];
+            let (entity_b, pose_b, collider_b, delta_b) = entities[j];
+
+            // 检测碰撞：先比较当前位置，未命中时再按相对位移做扫掠检测
+            let hit = check_collision(pose_a, collider_a, pose_b, collider_b).or_else(|| {
+                check_swept_collision(pose_a, collider_a, delta_a - delta_b, pose_b, collider_b)
+            });
+            if let Some((part_a, part_b)) = hit {
                 collision_events.write(CollisionEvent {
                     entity_a,
                     entity_b,
                     layer_a: collider_a.layer,
                     layer_b: collider_b.layer,
+                    sub_shape_a: collider_a.is_compound().then_some(part_a),
+                    sub_shape_b: collider_b.is_compound().then_some(part_b),
                 });
             }
+        },
+    );
+}
+
+/// 记录扫掠碰撞体本次检测时的位置，供下一帧计算位移
+fn record_swept_positions(mut query: Query<(&Transform, &mut SweptCollider)>) {
+    for (transform, mut swept) in query.iter_mut() {
+        swept.previous = Some(transform.translation.truncate());
+    }
+}
+
+/// 擦弹检测：敌方子弹进入玩家擦弹范围后记录，仍存活且未命中地离开范围时才结算，
+/// 在范围内消失（出界、被清弹等）的不算
+///
+/// 护盾是玩家的子实体（同为 PLAYER 层），只取顶层碰撞体作为玩家。
+fn detect_grazes(
+    mut commands: Commands,
+    players: Query<(Entity, &Transform, &Collider), Without<ChildOf>>,
+    bullets: Query<(Entity, &Transform, &Collider), Without<Grazed>>,
+    mut collision_events: MessageReader<CollisionEvent>,
+    mut tracked: Local<HashMap<Entity, GrazeTrack>>,
+    mut graze_events: MessageWriter<GrazeEvent>,
+) {
+    // 本帧命中玩家（含护盾）的子弹不算擦弹
+    for event in collision_events.read() {
+        let bullet = match (event.layer_a, event.layer_b) {
+            (CollisionLayer::PLAYER, CollisionLayer::ENEMY_BULLET) => event.entity_b,
+            (CollisionLayer::ENEMY_BULLET, CollisionLayer::PLAYER) => event.entity_a,
+            _ => continue,
+        };
+        tracked.remove(&bullet);
+    }
+
+    let players: Vec<_> = players
+        .iter()
+        .filter(|(_, _, c)| c.layer == CollisionLayer::PLAYER)
+        .collect();
+
+    let mut inside = HashSet::new();
+    for (bullet, bullet_tf, bullet_collider) in bullets.iter() {
+        if bullet_collider.layer != CollisionLayer::ENEMY_BULLET {
+            continue;
+        }
+        let bullet_pos = bullet_tf.translation.truncate();
+
+        let within = players
+            .iter()
+            .copied()
+            .find(|(_, player_tf, player_collider)| {
+                let reach = shape_bounding_radius(&player_collider.shape)
+                    + shape_bounding_radius(&bullet_collider.shape)
+                    + GRAZE_RADIUS;
+                let player_pos = player_tf.translation.truncate();
+                player_pos.distance_squared(bullet_pos) <= reach * reach
+            });
+        let Some((player, player_tf, player_collider)) = within else {
+            continue;
+        };
+        // 已经碰撞的交给碰撞处理（受伤），不算擦弹
+        if check_collision(
+            ColliderPose::from_transform(player_tf),
+            player_collider,
+            ColliderPose::from_transform(bullet_tf),
+            bullet_collider,
+        )
+        .is_some()
+        {
+            tracked.remove(&bullet);
+            continue;
+        }
+        inside.insert(bullet);
+        tracked.insert(
+            bullet,
+            GrazeTrack {
+                player,
+                position: bullet_pos,
+            },
+        );
+    }
+
+    // 仍存活且离开范围的子弹结算擦弹（子弹已消失或玩家已不在则作废）
+    tracked.retain(|&bullet, track| {
+        if inside.contains(&bullet) {
+            return true;
+        }
+        if bullets.contains(bullet) && players.iter().any(|(player, _, _)| *player == track.player)
+        {
+            commands.entity(bullet).try_insert(Grazed);
+            graze_events.write(GrazeEvent {
+                player: track.player,
+                bullet,
+                position: track.position,
+            });
+        }
+        false
+    });
+}
+
+/// 碰撞体的世界位姿：平移 + 绕 Z 轴旋转 + 缩放
+#[derive(Clone, Copy)]
+struct ColliderPose {
+    isometry: Isometry2d,
+    scale: Vec2,
+}
+
+impl ColliderPose {
+    fn from_transform(transform: &Transform) -> Self {
+        let (angle, _, _) = transform.rotation.to_euler(EulerRot::ZYX);
+        Self {
+            isometry: Isometry2d::new(transform.translation.truncate(), Rot2::radians(angle)),
+            scale: transform.scale.truncate(),
         }
     }
+
+    fn translation(&self) -> Vec2 {
+        self.isometry.translation
+    }
+
+    fn max_scale(&self) -> f32 {
+        self.scale.abs().max_element()
+    }
+
+    /// 本地坐标 → 世界坐标（先缩放，再旋转平移）
+    fn apply(&self, point: Vec2) -> Vec2 {
+        self.isometry.transform_point(point * self.scale)
+    }
 }
 
-/// 检查两个形状是否碰撞
+/// 检查两个碰撞体是否碰撞，返回双方命中的子形状索引（简单形状为 0）
+///
+/// 圆形与矩形之间沿用轴对齐的快速检测（尺寸随实体缩放，不随旋转）；
+/// 其余组合转换到世界坐标后做精确检测
+/// （多边形按凸块做分离轴检测，胶囊按线段距离检测）。复合碰撞箱按子形状顺序检测，
+/// 上报第一个命中的组合，因此弱点等优先部位应排在前面。
 fn check_collision(
-    pos_a: &Vec2,
-    shape_a: &CollisionShape,
-    pos_b: &Vec2,
-    shape_b: &CollisionShape,
-) -> bool {
-    match (shape_a, shape_b) {
+    pose_a: ColliderPose,
+    collider_a: &Collider,
+    pose_b: ColliderPose,
+    collider_b: &Collider,
+) -> Option<(usize, usize)> {
+    let (pos_a, scale_a) = (pose_a.translation(), pose_a.scale.abs());
+    let (pos_b, scale_b) = (pose_b.translation(), pose_b.scale.abs());
+    let hit = match (&collider_a.shape, &collider_b.shape) {
         (CollisionShape::Circle { radius: r_a }, CollisionShape::Circle { radius: r_b }) => {
-            circle_circle_collision(*pos_a, *r_a, *pos_b, *r_b)
+            circle_circle_collision(
+                pos_a,
+                r_a * pose_a.max_scale(),
+                pos_b,
+                r_b * pose_b.max_scale(),
+            )
         }
         (CollisionShape::Circle { radius }, CollisionShape::Rectangle { width, height }) => {
-            circle_rect_collision(*pos_a, *radius, *pos_b, *width, *height)
+            let size = Vec2::new(*width, *height) * scale_b;
+            circle_rect_collision(pos_a, radius * pose_a.max_scale(), pos_b, size.x, size.y)
         }
         (CollisionShape::Rectangle { width, height }, CollisionShape::Circle { radius }) => {
-            circle_rect_collision(*pos_b, *radius, *pos_a, *width, *height)
+            let size = Vec2::new(*width, *height) * scale_a;
+            circle_rect_collision(pos_b, radius * pose_b.max_scale(), pos_a, size.x, size.y)
         }
         (
             CollisionShape::Rectangle {
@@ -199,29 +632,112 @@ fn check_collision(
                 width: w_b,
                 height: h_b,
             },
-        ) => rect_rect_collision(*pos_a, *w_a, *h_a, *pos_b, *w_b, *h_b),
-        // 多边形碰撞简化为外接圆
-        (CollisionShape::Polygon { vertices: v_a }, CollisionShape::Polygon { vertices: v_b }) => {
-            let r_a = polygon_bounding_radius(v_a);
-            let r_b = polygon_bounding_radius(v_b);
-            circle_circle_collision(*pos_a, r_a, *pos_b, r_b)
-        }
-        (CollisionShape::Polygon { vertices }, CollisionShape::Circle { radius }) => {
-            let poly_r = polygon_bounding_radius(vertices);
-            circle_circle_collision(*pos_a, poly_r, *pos_b, *radius)
+        ) => {
+            let size_a = Vec2::new(*w_a, *h_a) * scale_a;
+            let size_b = Vec2::new(*w_b, *h_b) * scale_b;
+            rect_rect_collision(pos_a, size_a.x, size_a.y, pos_b, size_b.x, size_b.y)
         }
-        (CollisionShape::Circle { radius }, CollisionShape::Polygon { vertices }) => {
-            let poly_r = polygon_bounding_radius(vertices);
-            circle_circle_collision(*pos_a, *radius, *pos_b, poly_r)
+        _ => {
+            let shapes_a = world_shapes(pose_a, collider_a);
+            let shapes_b = world_shapes(pose_b, collider_b);
+            return shapes_a.iter().enumerate().find_map(|(i, a)| {
+                shapes_b
+                    .iter()
+                    .position(|b| a.intersects(b))
+                    .map(|j| (i, j))
+            });
         }
-        (CollisionShape::Polygon { vertices }, CollisionShape::Rectangle { width, height }) => {
-            let poly_r = polygon_bounding_radius(vertices);
-            circle_rect_collision(*pos_a, poly_r, *pos_b, *width, *height)
+    };
+    hit.then_some((0, 0))
+}
+
+/// 扫掠检测：A 相对 B 在本帧位移 `relative_delta`，检查 A 扫过的区域是否与 B 的当前形状相交
+///
+/// 位移小于双方中较小的外接半径时当前位置检测已足够，直接跳过
+/// （只看 A 的半径时，大体型 A 会让高速小子弹 B 穿过去）。
+fn check_swept_collision(
+    pose_a: ColliderPose,
+    collider_a: &Collider,
+    relative_delta: Vec2,
+    pose_b: ColliderPose,
+    collider_b: &Collider,
+) -> Option<(usize, usize)> {
+    let reach = (shape_bounding_radius(&collider_a.shape) * pose_a.max_scale())
+        .min(shape_bounding_radius(&collider_b.shape) * pose_b.max_scale());
+    if relative_delta.length_squared() <= reach * reach {
+        return None;
+    }
+
+    let shapes_a: Vec<_> = world_shapes(pose_a, collider_a)
+        .iter()
+        .map(|shape| shape.swept(relative_delta))
+        .collect();
+    let shapes_b = world_shapes(pose_b, collider_b);
+    shapes_a.iter().enumerate().find_map(|(i, a)| {
+        shapes_b
+            .iter()
+            .position(|b| a.intersects(b))
+            .map(|j| (i, j))
+    })
+}
+
+/// 把碰撞体的各子形状转换为世界坐标下的形状（按子形状索引排列）
+fn world_shapes(pose: ColliderPose, collider: &Collider) -> Vec<WorldShape> {
+    collider
+        .sub_shapes()
+        .into_iter()
+        .zip(collider.convex_parts.iter())
+        .map(|((offset, shape), convex)| world_shape(pose, offset.into(), shape, convex))
+        .collect()
+}
+
+/// 单个（已带偏移的）子形状转换到世界坐标
+fn world_shape(
+    pose: ColliderPose,
+    offset: Vec2,
+    shape: &CollisionShape,
+    convex: &[Vec<Vec2>],
+) -> WorldShape {
+    let box_corners = |half: Vec2| {
+        [
+            Vec2::new(-half.x, -half.y),
+            Vec2::new(half.x, -half.y),
+            Vec2::new(half.x, half.y),
+            Vec2::new(-half.x, half.y),
+        ]
+    };
+
+    match shape {
+        // 非均匀缩放下圆按最大缩放轴放大（不变形为椭圆）
+        CollisionShape::Circle { radius } => WorldShape::Circle {
+            center: pose.apply(offset),
+            radius: radius * pose.max_scale(),
+        },
+        CollisionShape::Rectangle { width, height } => {
+            let center = pose.apply(offset);
+            let corners = box_corners(Vec2::new(*width, *height) * pose.scale.abs() / 2.0);
+            WorldShape::Convex(vec![corners.iter().map(|c| center + *c).collect()])
         }
-        (CollisionShape::Rectangle { width, height }, CollisionShape::Polygon { vertices }) => {
-            let poly_r = polygon_bounding_radius(vertices);
-            circle_rect_collision(*pos_b, poly_r, *pos_a, *width, *height)
+        CollisionShape::Polygon { .. } => WorldShape::Convex(
+            convex
+                .iter()
+                .map(|part| part.iter().map(|p| pose.apply(offset + *p)).collect())
+                .collect(),
+        ),
+        CollisionShape::OrientedBox { width, height } => {
+            let corners = box_corners(Vec2::new(*width, *height) / 2.0);
+            WorldShape::Convex(vec![corners
+                .iter()
+                .map(|c| pose.apply(offset + *c))
+                .collect()])
         }
+        CollisionShape::Capsule { start, end, radius } => WorldShape::Capsule {
+            a: pose.apply(offset + Vec2::from(*start)),
+            b: pose.apply(offset + Vec2::from(*end)),
+            radius: radius * pose.max_scale(),
+        },
+        // 不支持嵌套复合形状
+        CollisionShape::Compound { .. } => WorldShape::Convex(Vec::new()),
     }
 }
 
@@ -263,6 +779,27 @@ fn rect_rect_collision(pos_a: Vec2, w_a: f32, h_a: f32, pos_b: Vec2, w_b: f32, h
         && (pos_a.y + half_ha) > (pos_b.y - half_hb)
 }
 
+/// 计算任意碰撞形状的外接圆半径
+fn shape_bounding_radius(shape: &CollisionShape) -> f32 {
+    match shape {
+        CollisionShape::Circle { radius } => *radius,
+        CollisionShape::Rectangle { width, height } => {
+            (width * width + height * height).sqrt() / 2.0
+        }
+        CollisionShape::Polygon { vertices } => polygon_bounding_radius(vertices),
+        CollisionShape::OrientedBox { width, height } => {
+            (width * width + height * height).sqrt() / 2.0
+        }
+        CollisionShape::Capsule { start, end, radius } => {
+            Vec2::from(*start).length().max(Vec2::from(*end).length()) + radius
+        }
+        CollisionShape::Compound { parts } => parts
+            .iter()
+            .map(|p| Vec2::from(p.offset).length() + shape_bounding_radius(&p.shape))
+            .fold(0.0f32, f32::max),
+    }
+}
+
 /// 计算多边形的外接圆半径
 fn polygon_bounding_radius(vertices: &[crate::geometry::Vec2D]) -> f32 {
     vertices
@@ -270,3 +807,79 @@ fn polygon_bounding_radius(vertices: &[crate::geometry::Vec2D]) -> f32 {
         .map(|v| (v.x * v.x + v.y * v.y).sqrt())
         .fold(0.0f32, |a, b| a.max(b))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::CollisionPart;
+
+    use super::*;
+
+    fn beam_entry(collider: &Collider, position: Vec2, half_width: f32) -> Option<f32> {
+        let transform = Transform::from_translation(position.extend(0.0));
+        collider.beam_entry(&transform, Vec2::ZERO, Vec2::Y, half_width, 500.0)
+    }
+
+    #[test]
+    fn beam_stops_at_polygon_edge_not_bounding_circle() {
+        // 尖角朝下的三角形：外接圆在 y = 100 - 50 处，实际尖角在 y = 100 - 40
+        let triangle = CollisionShape::Polygon {
+            vertices: vec![
+                Vec2D::new(0.0, -40.0),
+                Vec2D::new(50.0, 20.0),
+                Vec2D::new(-50.0, 20.0),
+            ],
+        };
+        let collider = Collider::new(triangle, CollisionLayer::ENEMY);
+        let entry = beam_entry(&collider, Vec2::new(0.0, 100.0), 1.0).unwrap();
+        assert!((entry - 60.0).abs() < 0.5, "entry = {entry}");
+    }
+
+    #[test]
+    fn beam_misses_polygon_inside_bounding_circle() {
+        // 直角在原点的三角形整体位于光束右侧，但外接圆（半径 40）覆盖光束
+        let triangle = CollisionShape::Polygon {
+            vertices: vec![
+                Vec2D::new(0.0, 0.0),
+                Vec2D::new(40.0, 0.0),
+                Vec2D::new(0.0, 40.0),
+            ],
+        };
+        let collider = Collider::new(triangle, CollisionLayer::ENEMY);
+        assert_eq!(beam_entry(&collider, Vec2::new(5.0, 100.0), 2.0), None);
+        let entry = beam_entry(&collider, Vec2::new(-20.0, 100.0), 2.0).unwrap();
+        assert!((entry - 100.0).abs() < 0.5, "entry = {entry}");
+    }
+
+    #[test]
+    fn compound_circle_radius_scales_with_pose() {
+        // 偏移 (10, 0)、半径 5 的子圆，放大 2 倍后圆心在 (20, 0)、半径 10
+        let compound = CollisionShape::Compound {
+            parts: vec![CollisionPart::new(
+                Vec2D::new(10.0, 0.0),
+                CollisionShape::Circle { radius: 5.0 },
+            )],
+        };
+        let boss = Collider::new(compound, CollisionLayer::ENEMY);
+        let boss_pose = ColliderPose::from_transform(&Transform::from_scale(Vec3::splat(2.0)));
+        let bullet = Collider::new(
+            CollisionShape::Circle { radius: 1.0 },
+            CollisionLayer::PLAYER_BULLET,
+        );
+        let at = |x: f32| ColliderPose::from_transform(&Transform::from_xyz(x, 0.0, 0.0));
+
+        assert_eq!(
+            check_collision(boss_pose, &boss, at(30.5), &bullet),
+            Some((0, 0))
+        );
+        assert_eq!(check_collision(boss_pose, &boss, at(31.5), &bullet), None);
+    }
+
+    #[test]
+    fn beam_ignores_targets_behind_origin() {
+        let collider = Collider::new(
+            CollisionShape::Circle { radius: 10.0 },
+            CollisionLayer::ENEMY,
+        );
+        assert_eq!(beam_entry(&collider, Vec2::new(0.0, -30.0), 2.0), None);
+    }
+}
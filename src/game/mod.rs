@@ -2,9 +2,12 @@
 //! 包含游戏状态、卷轴系统、碰撞检测等
 
 mod collision;
+mod narrowphase;
 mod scroll;
+mod spatial_hash;
 mod states;
 
 pub use collision::*;
 pub use scroll::*;
+pub use spatial_hash::*;
 pub use states::*;
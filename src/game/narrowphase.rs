@@ -0,0 +1,528 @@
+//! 窄相位几何：多边形凸分解、分离轴（SAT）与胶囊/线段距离检测
+//! 所有多边形统一为逆时针顶点顺序
+
+use bevy::prelude::*;
+
+use crate::geometry::Vec2D;
+
+const EPSILON: f32 = 1e-4;
+
+/// 把多边形拆成若干凸多边形（局部坐标，逆时针）
+///
+/// 凸多边形原样返回；凹多边形先耳切三角化，再合并相邻且合并后仍为凸的部分。
+/// 顶点不足 3 个或全部共线（退化形状）时返回空列表，即不参与碰撞。
+pub(crate) fn convex_decompose(vertices: &[Vec2D]) -> Vec<Vec<Vec2>> {
+    let mut points: Vec<Vec2> = vertices.iter().map(|v| Vec2::new(v.x, v.y)).collect();
+    points.dedup_by(|a, b| a.distance_squared(*b) < EPSILON);
+    if points.len() > 1 && points[0].distance_squared(points[points.len() - 1]) < EPSILON {
+        points.pop();
+    }
+    if points.len() < 3 {
+        return Vec::new();
+    }
+
+    // 所有顶点共线（面积为零）同样视为退化形状
+    let area = signed_area(&points);
+    if area.abs() <= EPSILON {
+        return Vec::new();
+    }
+    if area < 0.0 {
+        points.reverse();
+    }
+    if is_convex(&points) {
+        return vec![points];
+    }
+
+    merge_convex(triangulate(&points))
+}
+
+/// 世界坐标下的碰撞形状
+pub(crate) enum WorldShape {
+    Circle {
+        center: Vec2,
+        radius: f32,
+    },
+    /// 一个或多个凸多边形
+    Convex(Vec<Vec<Vec2>>),
+    Capsule {
+        a: Vec2,
+        b: Vec2,
+        radius: f32,
+    },
+    /// 多个形状的并集（扫掠胶囊等无法用单一凸形表示的形状）
+    Union(Vec<WorldShape>),
+}
+
+impl WorldShape {
+    pub(crate) fn intersects(&self, other: &WorldShape) -> bool {
+        use WorldShape::*;
+        match (self, other) {
+            (Union(shapes), other) | (other, Union(shapes)) => {
+                shapes.iter().any(|shape| shape.intersects(other))
+            }
+            (
+                Circle {
+                    center: c_a,
+                    radius: r_a,
+                },
+                Circle {
+                    center: c_b,
+                    radius: r_b,
+                },
+            ) => c_a.distance_squared(*c_b) <= (r_a + r_b) * (r_a + r_b),
+            (Circle { center, radius }, Convex(parts))
+            | (Convex(parts), Circle { center, radius }) => parts
+                .iter()
+                .any(|part| polygon_circle_intersect(part, *center, *radius)),
+            (
+                Circle {
+                    center,
+                    radius: r_circle,
+                },
+                Capsule { a, b, radius },
+            )
+            | (
+                Capsule { a, b, radius },
+                Circle {
+                    center,
+                    radius: r_circle,
+                },
+            ) => closest_point_on_segment(*center, *a, *b).distance(*center) <= r_circle + radius,
+            (Convex(parts_a), Convex(parts_b)) => parts_a
+                .iter()
+                .any(|a| parts_b.iter().any(|b| polygons_intersect(a, b))),
+            (Convex(parts), Capsule { a, b, radius })
+            | (Capsule { a, b, radius }, Convex(parts)) => parts
+                .iter()
+                .any(|part| polygon_segment_distance(part, *a, *b) <= *radius),
+            (
+                Capsule {
+                    a: a1,
+                    b: b1,
+                    radius: r1,
+                },
+                Capsule {
+                    a: a2,
+                    b: b2,
+                    radius: r2,
+                },
+            ) => segment_segment_distance(*a1, *b1, *a2, *b2) <= r1 + r2,
+        }
+    }
+
+    /// 形状从 `self - delta` 平移到 `self` 所扫过的区域（不考虑期间的旋转）
+    ///
+    /// 圆扫成胶囊；凸多边形取起止位置的凸包；胶囊为平行四边形加四条边上的胶囊。
+    pub(crate) fn swept(&self, delta: Vec2) -> WorldShape {
+        use WorldShape::*;
+        match self {
+            Circle { center, radius } => Capsule {
+                a: *center - delta,
+                b: *center,
+                radius: *radius,
+            },
+            Convex(parts) => Convex(
+                parts
+                    .iter()
+                    .map(|part| {
+                        let mut points = part.clone();
+                        points.extend(part.iter().map(|p| *p - delta));
+                        convex_hull(points)
+                    })
+                    .collect(),
+            ),
+            Capsule { a, b, radius } => {
+                let (a0, b0) = (*a - delta, *b - delta);
+                let edge = |a, b| Capsule {
+                    a,
+                    b,
+                    radius: *radius,
+                };
+                Union(vec![
+                    Convex(vec![convex_hull(vec![a0, b0, *a, *b])]),
+                    edge(a0, b0),
+                    edge(*a, *b),
+                    edge(a0, *a),
+                    edge(b0, *b),
+                ])
+            }
+            Union(shapes) => Union(shapes.iter().map(|shape| shape.swept(delta)).collect()),
+        }
+    }
+}
+
+/// 点集的凸包（Andrew 单调链，逆时针）；退化为线段或点时返回少于 3 个顶点
+fn convex_hull(mut points: Vec<Vec2>) -> Vec<Vec2> {
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup_by(|a, b| a.distance_squared(*b) < EPSILON);
+    if points.len() < 3 {
+        return points;
+    }
+
+    // 下链从左到右、上链从右到左，拼起来即为逆时针
+    let mut hull: Vec<Vec2> = Vec::with_capacity(points.len() + 1);
+    for upper in [false, true] {
+        let start = hull.len();
+        let chain: Vec<Vec2> = if upper {
+            points.iter().rev().copied().collect()
+        } else {
+            points.clone()
+        };
+        for p in chain {
+            while hull.len() >= start + 2 && turn(&hull, p) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // 每条链的终点是另一条链的起点
+        hull.pop();
+    }
+    hull
+}
+
+/// 链尾两个点与新点构成的转向（正值为左转）
+fn turn(chain: &[Vec2], p: Vec2) -> f32 {
+    let n = chain.len();
+    cross(chain[n - 1] - chain[n - 2], p - chain[n - 1])
+}
+
+/// 两个凸多边形是否相交（分离轴定理）
+fn polygons_intersect(a: &[Vec2], b: &[Vec2]) -> bool {
+    !has_separating_axis(a, b) && !has_separating_axis(b, a)
+}
+
+/// 凸多边形与圆是否相交
+fn polygon_circle_intersect(poly: &[Vec2], center: Vec2, radius: f32) -> bool {
+    // 圆心在多边形内部
+    if point_in_convex(poly, center) {
+        return true;
+    }
+
+    let n = poly.len();
+    let radius_sq = radius * radius;
+    (0..n).any(|i| {
+        closest_point_on_segment(center, poly[i], poly[(i + 1) % n]).distance_squared(center)
+            <= radius_sq
+    })
+}
+
+/// 线段到凸多边形的最短距离（相交或在内部为 0）
+fn polygon_segment_distance(poly: &[Vec2], a: Vec2, b: Vec2) -> f32 {
+    if point_in_convex(poly, a) || point_in_convex(poly, b) {
+        return 0.0;
+    }
+    let n = poly.len();
+    (0..n)
+        .map(|i| segment_segment_distance(a, b, poly[i], poly[(i + 1) % n]))
+        .fold(f32::MAX, f32::min)
+}
+
+/// 两线段间最短距离（相交为 0）
+fn segment_segment_distance(p1: Vec2, q1: Vec2, p2: Vec2, q2: Vec2) -> f32 {
+    let d1 = cross(q2 - p2, p1 - p2);
+    let d2 = cross(q2 - p2, q1 - p2);
+    let d3 = cross(q1 - p1, p2 - p1);
+    let d4 = cross(q1 - p1, q2 - p1);
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return 0.0;
+    }
+
+    // 不相交（或共线接触）时，最短距离必出现在某个端点
+    [
+        closest_point_on_segment(p1, p2, q2).distance(p1),
+        closest_point_on_segment(q1, p2, q2).distance(q1),
+        closest_point_on_segment(p2, p1, q1).distance(p2),
+        closest_point_on_segment(q2, p1, q1).distance(q2),
+    ]
+    .into_iter()
+    .fold(f32::MAX, f32::min)
+}
+
+/// 点是否在逆时针凸多边形内（含边界）
+fn point_in_convex(poly: &[Vec2], p: Vec2) -> bool {
+    let n = poly.len();
+    n >= 3 && (0..n).all(|i| cross(poly[(i + 1) % n] - poly[i], p - poly[i]) >= 0.0)
+}
+
+/// 以 a 的各条边法线为轴，检查是否存在分离轴
+fn has_separating_axis(a: &[Vec2], b: &[Vec2]) -> bool {
+    let n = a.len();
+    (0..n).any(|i| {
+        let axis = (a[(i + 1) % n] - a[i]).perp();
+        let (min_a, max_a) = project(a, axis);
+        let (min_b, max_b) = project(b, axis);
+        max_a < min_b || max_b < min_a
+    })
+}
+
+/// 多边形在轴上的投影区间
+fn project(poly: &[Vec2], axis: Vec2) -> (f32, f32) {
+    poly.iter()
+        .map(|p| p.dot(axis))
+        .fold((f32::MAX, f32::MIN), |(min, max), d| {
+            (min.min(d), max.max(d))
+        })
+}
+
+fn closest_point_on_segment(p: Vec2, a: Vec2, b: Vec2) -> Vec2 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq <= f32::EPSILON {
+        return a;
+    }
+    let t = ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    a + ab * t
+}
+
+fn cross(a: Vec2, b: Vec2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+/// 有向面积（逆时针为正）
+fn signed_area(points: &[Vec2]) -> f32 {
+    let n = points.len();
+    (0..n)
+        .map(|i| cross(points[i], points[(i + 1) % n]))
+        .sum::<f32>()
+        * 0.5
+}
+
+/// 逆时针多边形是否为凸（允许共线）
+fn is_convex(points: &[Vec2]) -> bool {
+    let n = points.len();
+    (0..n).all(|i| {
+        let prev = points[(i + n - 1) % n];
+        let cur = points[i];
+        let next = points[(i + 1) % n];
+        cross(cur - prev, next - cur) >= -EPSILON
+    })
+}
+
+fn point_in_triangle(p: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    cross(b - a, p - a) >= 0.0 && cross(c - b, p - b) >= 0.0 && cross(a - c, p - c) >= 0.0
+}
+
+/// 耳切法三角化（输入为逆时针简单多边形）
+fn triangulate(points: &[Vec2]) -> Vec<Vec<Vec2>> {
+    let mut indices: Vec<usize> = (0..points.len()).collect();
+    let mut triangles = Vec::new();
+
+    while indices.len() > 3 {
+        let n = indices.len();
+        let mut clipped = false;
+
+        for i in 0..n {
+            let prev = points[indices[(i + n - 1) % n]];
+            let cur = points[indices[i]];
+            let next = points[indices[(i + 1) % n]];
+            let turn = cross(cur - prev, next - cur);
+
+            // 共线顶点直接移除
+            if turn.abs() <= EPSILON {
+                indices.remove(i);
+                clipped = true;
+                break;
+            }
+            // 凹顶点不是耳朵
+            if turn < 0.0 {
+                continue;
+            }
+            let blocked = indices.iter().any(|&k| {
+                let p = points[k];
+                p != prev && p != cur && p != next && point_in_triangle(p, prev, cur, next)
+            });
+            if blocked {
+                continue;
+            }
+
+            triangles.push(vec![prev, cur, next]);
+            indices.remove(i);
+            clipped = true;
+            break;
+        }
+
+        // 自相交等异常输入：找不到耳朵时放弃剩余部分
+        if !clipped {
+            break;
+        }
+    }
+
+    if indices.len() == 3 {
+        let tri: Vec<Vec2> = indices.iter().map(|&k| points[k]).collect();
+        if signed_area(&tri) > EPSILON {
+            triangles.push(tri);
+        }
+    }
+    triangles
+}
+
+/// 贪心合并共享边的凸块（Hertel-Mehlhorn 思路），减少 SAT 次数
+fn merge_convex(mut parts: Vec<Vec<Vec2>>) -> Vec<Vec<Vec2>> {
+    let mut merged = true;
+    while merged {
+        merged = false;
+        'search: for a in 0..parts.len() {
+            for b in (a + 1)..parts.len() {
+                if let Some(poly) = merge_along_shared_edge(&parts[a], &parts[b]) {
+                    if is_convex(&poly) {
+                        parts[a] = poly;
+                        parts.swap_remove(b);
+                        merged = true;
+                        break 'search;
+                    }
+                }
+            }
+        }
+    }
+    parts
+}
+
+/// 若两逆时针多边形共享一条边（方向相反），返回拼接后的多边形
+fn merge_along_shared_edge(a: &[Vec2], b: &[Vec2]) -> Option<Vec<Vec2>> {
+    for i in 0..a.len() {
+        let a0 = a[i];
+        let a1 = a[(i + 1) % a.len()];
+        for j in 0..b.len() {
+            if b[j] != a1 || b[(j + 1) % b.len()] != a0 {
+                continue;
+            }
+            // a: a1 → … → a0，然后接上 b 中除公共边外的顶点
+            let mut poly: Vec<Vec2> = (0..a.len()).map(|k| a[(i + 1 + k) % a.len()]).collect();
+            poly.extend((2..b.len()).map(|k| b[(j + k) % b.len()]));
+            return Some(poly);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(center: Vec2, half: f32) -> Vec<Vec2> {
+        vec![
+            center + Vec2::new(-half, -half),
+            center + Vec2::new(half, -half),
+            center + Vec2::new(half, half),
+            center + Vec2::new(-half, half),
+        ]
+    }
+
+    fn convex(poly: Vec<Vec2>) -> WorldShape {
+        WorldShape::Convex(vec![poly])
+    }
+
+    fn circle(x: f32, y: f32, radius: f32) -> WorldShape {
+        WorldShape::Circle {
+            center: Vec2::new(x, y),
+            radius,
+        }
+    }
+
+    fn vertices(points: &[(f32, f32)]) -> Vec<Vec2D> {
+        points.iter().map(|&(x, y)| Vec2D::new(x, y)).collect()
+    }
+
+    fn contains(parts: &[Vec<Vec2>], p: Vec2) -> bool {
+        parts.iter().any(|part| point_in_convex(part, p))
+    }
+
+    #[test]
+    fn circle_polygon() {
+        let poly = convex(square(Vec2::ZERO, 1.0));
+        // 圆心在内部
+        assert!(poly.intersects(&circle(0.2, 0.3, 0.1)));
+        // 与边重叠 / 刚好相切 / 分离
+        assert!(poly.intersects(&circle(1.9, 0.0, 1.0)));
+        assert!(poly.intersects(&circle(2.0, 0.0, 1.0)));
+        assert!(!poly.intersects(&circle(2.1, 0.0, 1.0)));
+        // 角点外侧：轴向投影重叠但离角点超过半径
+        assert!(!poly.intersects(&circle(1.8, 1.8, 1.0)));
+        assert!(circle(1.6, 1.6, 1.0).intersects(&poly));
+    }
+
+    #[test]
+    fn polygon_polygon_separated() {
+        let a = convex(square(Vec2::ZERO, 1.0));
+        let b = convex(square(Vec2::new(2.5, 0.0), 1.0));
+        assert!(!a.intersects(&b));
+        assert!(!b.intersects(&a));
+
+        // 坐标轴上的投影重叠，只能由菱形的斜边法线分离
+        let diamond = convex(vec![
+            Vec2::new(2.0, 0.8),
+            Vec2::new(3.2, 2.0),
+            Vec2::new(2.0, 3.2),
+            Vec2::new(0.8, 2.0),
+        ]);
+        let corner = convex(square(Vec2::ZERO, 1.0));
+        assert!(!corner.intersects(&diamond));
+    }
+
+    #[test]
+    fn polygon_polygon_touching() {
+        let a = convex(square(Vec2::ZERO, 1.0));
+        // 共用一条边
+        assert!(a.intersects(&convex(square(Vec2::new(2.0, 0.0), 1.0))));
+        // 只碰到一个角
+        assert!(a.intersects(&convex(square(Vec2::new(2.0, 2.0), 1.0))));
+    }
+
+    #[test]
+    fn polygon_polygon_overlapping() {
+        let a = convex(square(Vec2::ZERO, 1.0));
+        assert!(a.intersects(&convex(square(Vec2::new(1.5, 0.5), 1.0))));
+        // 完全包含
+        assert!(a.intersects(&convex(square(Vec2::ZERO, 0.2))));
+    }
+
+    #[test]
+    fn decompose_concave_l_shape() {
+        let l_shape = vertices(&[
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (0.0, 2.0),
+        ]);
+        let parts = convex_decompose(&l_shape);
+
+        assert!(parts.len() >= 2);
+        for part in &parts {
+            assert!(is_convex(part));
+            assert!(signed_area(part) > 0.0);
+        }
+        let area: f32 = parts.iter().map(|part| signed_area(part)).sum();
+        assert!((area - 3.0).abs() < 1e-3);
+        // 凹口处不属于任何凸块，两条臂都被覆盖
+        assert!(!contains(&parts, Vec2::new(1.5, 1.5)));
+        assert!(contains(&parts, Vec2::new(1.5, 0.5)));
+        assert!(contains(&parts, Vec2::new(0.5, 1.5)));
+    }
+
+    #[test]
+    fn decompose_normalizes_winding_and_keeps_convex_shapes() {
+        // 顺时针输入转为逆时针，凸多边形整体保留
+        let clockwise = vertices(&[(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)]);
+        let parts = convex_decompose(&clockwise);
+        assert_eq!(parts.len(), 1);
+        assert!(signed_area(&parts[0]) > 0.0);
+
+        // 边上的共线顶点不影响凸性
+        let with_midpoint = vertices(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+        let parts = convex_decompose(&with_midpoint);
+        assert_eq!(parts.len(), 1);
+        assert!((signed_area(&parts[0]) - 4.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn decompose_degenerate_polygons() {
+        // 重复顶点去重后不足 3 个
+        assert!(convex_decompose(&vertices(&[(0.0, 0.0), (0.0, 0.0), (1.0, 0.0)])).is_empty());
+        // 首尾重复
+        assert!(convex_decompose(&vertices(&[(0.0, 0.0), (1.0, 1.0), (0.0, 0.0)])).is_empty());
+        // 全部共线
+        assert!(convex_decompose(&vertices(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)])).is_empty());
+    }
+}
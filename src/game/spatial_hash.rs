@@ -0,0 +1,220 @@
+//! 空间哈希（均匀网格）宽相位
+//! 每帧重建：按包围盒把碰撞体放入覆盖的网格，只对同格内的对象做进一步检测
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// 默认网格边长（像素），略大于常见敌机尺寸
+pub const COLLISION_CELL_SIZE: f32 = 64.0;
+
+/// 单个碰撞体最多占用的网格数（每个轴），避免全屏光柱等超大形状拖慢插入
+const MAX_CELLS_PER_AXIS: i32 = 32;
+
+/// 均匀网格空间哈希
+pub struct SpatialHash {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl Default for SpatialHash {
+    fn default() -> Self {
+        Self::new(COLLISION_CELL_SIZE)
+    }
+}
+
+impl SpatialHash {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size: cell_size.max(1.0),
+            cells: HashMap::default(),
+        }
+    }
+
+    /// 清空网格（保留上一帧仍在使用的格子的内存，丢弃空格子）
+    pub fn clear(&mut self) {
+        self.cells.retain(|_, list| {
+            let keep = !list.is_empty();
+            list.clear();
+            keep
+        });
+    }
+
+    /// 计算点所在的网格坐标
+    pub fn cell_of(&self, point: Vec2) -> (i32, i32) {
+        (
+            (point.x / self.cell_size).floor() as i32,
+            (point.y / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// 按包围盒插入对象索引
+    pub fn insert(&mut self, index: usize, min: Vec2, max: Vec2) {
+        let (x0, y0) = self.cell_of(min);
+        let (x1, y1) = self.cell_of(max);
+        let x1 = x1.min(x0 + MAX_CELLS_PER_AXIS);
+        let y1 = y1.min(y0 + MAX_CELLS_PER_AXIS);
+
+        for x in x0..=x1 {
+            for y in y0..=y1 {
+                self.cells.entry((x, y)).or_default().push(index);
+            }
+        }
+    }
+
+    /// 遍历所有包围盒重叠的候选对（每对只回调一次，i < j）
+    ///
+    /// `bounds` 为插入时使用的 (min, max)，按索引对应；`filter` 在包围盒测试前调用，
+    /// 用于按碰撞层快速剔除不可能产生碰撞的组合。
+    /// 去重方式：只在“两包围盒交集左下角所在的格子”里上报这一对。
+    pub fn for_each_pair(
+        &self,
+        bounds: &[(Vec2, Vec2)],
+        filter: impl Fn(usize, usize) -> bool,
+        mut f: impl FnMut(usize, usize),
+    ) {
+        for (&cell, list) in self.cells.iter() {
+            for a in 0..list.len() {
+                let i = list[a];
+                let (min_i, max_i) = bounds[i];
+                for &j in &list[a + 1..] {
+                    if !filter(i, j) {
+                        continue;
+                    }
+                    let (min_j, max_j) = bounds[j];
+                    if min_i.x > max_j.x
+                        || max_i.x < min_j.x
+                        || min_i.y > max_j.y
+                        || max_i.y < min_j.y
+                    {
+                        continue;
+                    }
+                    if self.pair_owner_cell(min_i, min_j) != cell {
+                        continue;
+                    }
+                    f(i.min(j), i.max(j));
+                }
+            }
+        }
+    }
+
+    /// 一对对象的“归属格子”：交集左下角所在格子，按插入时的截断范围收紧
+    fn pair_owner_cell(&self, min_i: Vec2, min_j: Vec2) -> (i32, i32) {
+        let (px, py) = self.cell_of(min_i.max(min_j));
+        let (ix, iy) = self.cell_of(min_i);
+        let (jx, jy) = self.cell_of(min_j);
+        (
+            px.min(ix.min(jx) + MAX_CELLS_PER_AXIS),
+            py.min(iy.min(jy) + MAX_CELLS_PER_AXIS),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(grid: &SpatialHash, bounds: &[(Vec2, Vec2)]) -> Vec<(usize, usize)> {
+        let mut found = Vec::new();
+        grid.for_each_pair(bounds, |_, _| true, |i, j| found.push((i, j)));
+        found.sort();
+        found
+    }
+
+    fn build(bounds: &[(Vec2, Vec2)]) -> SpatialHash {
+        let mut grid = SpatialHash::new(10.0);
+        for (index, (min, max)) in bounds.iter().enumerate() {
+            grid.insert(index, *min, *max);
+        }
+        grid
+    }
+
+    fn occupied(grid: &SpatialHash, index: usize) -> Vec<(i32, i32)> {
+        let mut cells: Vec<_> = grid
+            .cells
+            .iter()
+            .filter(|(_, list)| list.contains(&index))
+            .map(|(&cell, _)| cell)
+            .collect();
+        cells.sort();
+        cells
+    }
+
+    #[test]
+    fn cell_of_floors_negative_coordinates() {
+        let grid = SpatialHash::new(10.0);
+        assert_eq!(grid.cell_of(Vec2::new(0.0, 9.9)), (0, 0));
+        assert_eq!(grid.cell_of(Vec2::new(10.0, 25.0)), (1, 2));
+        assert_eq!(grid.cell_of(Vec2::new(-0.1, -10.0)), (-1, -1));
+        assert_eq!(grid.cell_of(Vec2::new(-10.1, 0.0)), (-2, 0));
+    }
+
+    #[test]
+    fn insert_into_single_cell() {
+        let grid = build(&[(Vec2::new(1.0, 1.0), Vec2::new(4.0, 4.0))]);
+        assert_eq!(occupied(&grid, 0), vec![(0, 0)]);
+    }
+
+    #[test]
+    fn insert_spans_multiple_cells() {
+        let grid = build(&[(Vec2::new(-5.0, 5.0), Vec2::new(15.0, 12.0))]);
+        assert_eq!(
+            occupied(&grid, 0),
+            vec![(-1, 0), (-1, 1), (0, 0), (0, 1), (1, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn insert_clamps_huge_bounds() {
+        let grid = build(&[(Vec2::ZERO, Vec2::new(10_000.0, 5.0))]);
+        let cells = occupied(&grid, 0);
+        assert_eq!(cells.len(), MAX_CELLS_PER_AXIS as usize + 1);
+    }
+
+    #[test]
+    fn pair_spanning_many_shared_cells_is_reported_once() {
+        // 两个大包围盒共享 3×3 个格子
+        let bounds = [
+            (Vec2::new(0.0, 0.0), Vec2::new(29.0, 29.0)),
+            (Vec2::new(5.0, 5.0), Vec2::new(35.0, 35.0)),
+        ];
+        let grid = build(&bounds);
+        assert_eq!(pairs(&grid, &bounds), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn same_cell_without_overlap_is_skipped() {
+        let bounds = [
+            (Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0)),
+            (Vec2::new(6.0, 6.0), Vec2::new(8.0, 8.0)),
+            (Vec2::new(1.0, 1.0), Vec2::new(7.0, 7.0)),
+        ];
+        let grid = build(&bounds);
+        assert_eq!(pairs(&grid, &bounds), vec![(0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn filter_rejects_pairs() {
+        let bounds = [
+            (Vec2::new(0.0, 0.0), Vec2::new(5.0, 5.0)),
+            (Vec2::new(1.0, 1.0), Vec2::new(6.0, 6.0)),
+        ];
+        let grid = build(&bounds);
+        let mut found = 0;
+        grid.for_each_pair(&bounds, |_, _| false, |_, _| found += 1);
+        assert_eq!(found, 0);
+    }
+
+    #[test]
+    fn clear_resets_between_frames() {
+        let bounds = [
+            (Vec2::new(0.0, 0.0), Vec2::new(5.0, 5.0)),
+            (Vec2::new(1.0, 1.0), Vec2::new(6.0, 6.0)),
+        ];
+        let mut grid = build(&bounds);
+        grid.clear();
+        assert!(pairs(&grid, &bounds).is_empty());
+        grid.insert(0, bounds[0].0, bounds[0].1);
+        grid.insert(1, bounds[1].0, bounds[1].1);
+        assert_eq!(pairs(&grid, &bounds), vec![(0, 1)]);
+    }
+}
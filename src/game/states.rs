@@ -23,6 +23,18 @@ pub enum GameState {
     Enhance,
 }
 
+/// 单局统计（每次开局重置，用于结算界面）
+#[derive(Debug, Clone, Default)]
+pub struct RunStats {
+    /// 擦弹次数
+    pub grazes: u32,
+    /// 最大连击数
+    pub max_combo: u32,
+}
+
+/// 连击超时时间（秒）：超过该时间没有击杀则连击中断
+pub const COMBO_TIMEOUT: f32 = 2.5;
+
 /// 游戏数据资源
 #[derive(Resource, Default)]
 pub struct GameData {
@@ -46,10 +58,20 @@ pub struct GameData {
     pub play_time: f32,
     /// 当前经验值
     pub experience: u32,
+    /// 经验获取倍率（被动道具）
+    pub exp_multiplier: f32,
     /// 战机等级
     pub player_level: u32,
     /// 是否正在升级选择
     pub upgrading: bool,
+    /// 当前连击数
+    pub combo: u32,
+    /// 连击剩余时间（秒）
+    pub combo_timer: f32,
+    /// 本局统计
+    pub run_stats: RunStats,
+    /// 尚未以 LevelUp 事件发出的新等级
+    pending_level_ups: Vec<u32>,
 }
 
 impl GameData {
@@ -65,8 +87,13 @@ impl GameData {
             max_shield: 4,
             play_time: 0.0,
             experience: 0,
+            exp_multiplier: 1.0,
             player_level: 1,
             upgrading: false,
+            combo: 0,
+            combo_timer: 0.0,
+            run_stats: RunStats::default(),
+            pending_level_ups: Vec::new(),
         }
     }
 
@@ -80,8 +107,13 @@ impl GameData {
         self.max_shield = 4;
         self.play_time = 0.0;
         self.experience = 0;
+        self.exp_multiplier = 1.0;
         self.player_level = 1;
         self.upgrading = false;
+        self.combo = 0;
+        self.combo_timer = 0.0;
+        self.run_stats = RunStats::default();
+        self.pending_level_ups.clear();
     }
 
     pub fn add_score(&mut self, points: u32) {
@@ -101,6 +133,41 @@ impl GameData {
         }
     }
 
+    /// 击杀得分：累计连击，分数按连击倍率放大（经验值不受倍率影响）
+    pub fn add_kill_score(&mut self, points: u32) {
+        self.register_kill();
+        self.add_score_only(self.combo_score(points));
+        self.add_experience(points);
+    }
+
+    /// 击杀得分（不增加经验，如 Boss 战期间的小兵）
+    pub fn add_kill_score_only(&mut self, points: u32) {
+        self.register_kill();
+        self.add_score_only(self.combo_score(points));
+    }
+
+    /// 连击 +1 并刷新超时
+    fn register_kill(&mut self) {
+        self.combo += 1;
+        self.combo_timer = COMBO_TIMEOUT;
+        self.run_stats.max_combo = self.run_stats.max_combo.max(self.combo);
+    }
+
+    /// 当前连击倍率：每次连击 +5%，最高 3 倍
+    pub fn combo_multiplier(&self) -> f32 {
+        (1.0 + self.combo.saturating_sub(1) as f32 * 0.05).min(3.0)
+    }
+
+    fn combo_score(&self, points: u32) -> u32 {
+        (points as f32 * self.combo_multiplier()).round() as u32
+    }
+
+    /// 中断连击（超时或受伤）
+    pub fn break_combo(&mut self) {
+        self.combo = 0;
+        self.combo_timer = 0.0;
+    }
+
     /// 计算升级所需经验值（曲线公式）
     pub fn exp_for_level(level: u32) -> u32 {
         // 经验曲线：base * level^1.5
@@ -108,18 +175,24 @@ impl GameData {
         (base * (level as f32).powf(1.5)) as u32
     }
 
-    /// 添加经验值并检查升级
+    /// 添加经验值（按经验倍率放大）并检查升级
     pub fn add_experience(&mut self, exp: u32) {
-        self.experience += exp;
+        self.experience += (exp as f32 * self.exp_multiplier).round() as u32;
         let required = Self::exp_for_level(self.player_level);
         if self.experience >= required {
             self.experience -= required;
             self.player_level += 1;
             self.upgrading = true; // 标记需要升级选择
+            self.pending_level_ups.push(self.player_level);
             log::info!("Level up! Now level {}", self.player_level);
         }
     }
 
+    /// 取出上次调用以来提升到的各个等级（按先后顺序）
+    pub fn take_level_ups(&mut self) -> Vec<u32> {
+        std::mem::take(&mut self.pending_level_ups)
+    }
+
     /// 经验值进度 (0.0 - 1.0)
     pub fn exp_progress(&self) -> f32 {
         let required = Self::exp_for_level(self.player_level);
@@ -192,6 +265,12 @@ impl Plugin for GameStatePlugin {
             .add_systems(
                 Update,
                 (update_game_time, handle_pause_input).run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                update_combo_timer
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not_upgrading),
             );
     }
 }
@@ -226,6 +305,16 @@ fn update_game_time(time: Res<Time>, mut game_data: ResMut<GameData>) {
     game_data.play_time += time.delta_secs();
 }
 
+fn update_combo_timer(time: Res<Time>, mut game_data: ResMut<GameData>) {
+    if game_data.combo == 0 {
+        return;
+    }
+    game_data.combo_timer -= time.delta_secs();
+    if game_data.combo_timer <= 0.0 {
+        game_data.break_combo();
+    }
+}
+
 fn handle_pause_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<GameState>>,
@@ -130,6 +130,9 @@ pub enum GeometryShape {
 }
 
 /// 碰撞形状
+///
+/// Circle / Rectangle 为轴对齐的快速形状，忽略实体旋转与缩放；
+/// Polygon / OrientedBox / Capsule 会随实体 Transform 旋转和缩放。
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CollisionShape {
     /// 圆形碰撞箱
@@ -138,6 +141,30 @@ pub enum CollisionShape {
     Rectangle { width: f32, height: f32 },
     /// 多边形碰撞箱
     Polygon { vertices: Vec<Vec2D> },
+    /// 有向矩形（宽沿本地 X 轴，高沿本地 Y 轴）
+    OrientedBox { width: f32, height: f32 },
+    /// 胶囊：本地线段 start → end 向外扩展 radius
+    Capsule {
+        start: Vec2D,
+        end: Vec2D,
+        radius: f32,
+    },
+    /// 复合碰撞箱：多个带偏移的子形状（子形状不应再嵌套 Compound）
+    Compound { parts: Vec<CollisionPart> },
+}
+
+/// 复合碰撞箱中的子形状
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollisionPart {
+    /// 相对实体中心的偏移（随实体旋转/缩放）
+    pub offset: Vec2D,
+    pub shape: CollisionShape,
+}
+
+impl CollisionPart {
+    pub fn new(offset: Vec2D, shape: CollisionShape) -> Self {
+        Self { offset, shape }
+    }
 }
 
 impl Default for CollisionShape {
@@ -595,7 +622,12 @@ impl GeometryBlueprint {
                     stroke_width: 1.0,
                 },
             ],
-            collision: CollisionShape::Circle { radius: 6.5 },
+            // 沿针身（本地 Y 轴）的细胶囊，随子弹朝向旋转
+            collision: CollisionShape::Capsule {
+                start: Vec2D::new(0.0, -7.5),
+                end: Vec2D::new(0.0, 7.5),
+                radius: 2.5,
+            },
             scale: 1.0,
         }
     }
@@ -928,6 +960,152 @@ impl GeometryBlueprint {
         }
     }
 
+    /// 回旋刃：V 形双刃（用于 Boomerang）
+    pub fn boomerang_blade() -> Self {
+        Self {
+            name: "boomerang_blade".to_string(),
+            shapes: vec![
+                GeometryShape::Polygon {
+                    vertices: vec![
+                        Vec2D::new(0.0, 7.0),
+                        Vec2D::new(16.0, -7.0),
+                        Vec2D::new(12.0, -11.0),
+                        Vec2D::new(0.0, -2.0),
+                        Vec2D::new(-12.0, -11.0),
+                        Vec2D::new(-16.0, -7.0),
+                    ],
+                    color: ShapeColor::new(0.9, 0.95, 0.6, 0.85),
+                    fill: true,
+                    stroke_width: 1.0,
+                },
+                GeometryShape::Circle {
+                    center: Vec2D::ZERO,
+                    radius: 3.0,
+                    color: ShapeColor::new(1.0, 1.0, 1.0, 0.9),
+                    fill: true,
+                    stroke_width: 1.0,
+                },
+            ],
+            collision: CollisionShape::Circle { radius: 12.0 },
+            scale: 1.0,
+        }
+    }
+
+    /// 感应雷：带尖刺的圆形地雷（用于 Mine）
+    pub fn proximity_mine() -> Self {
+        let mut shapes: Vec<GeometryShape> = (0..8)
+            .map(|i| {
+                let angle = i as f32 * PI / 4.0;
+                GeometryShape::Line {
+                    start: Vec2D::new(angle.cos() * 7.0, angle.sin() * 7.0),
+                    end: Vec2D::new(angle.cos() * 11.0, angle.sin() * 11.0),
+                    color: ShapeColor::new(0.75, 0.75, 0.8, 0.9),
+                    stroke_width: 2.0,
+                }
+            })
+            .collect();
+        shapes.push(GeometryShape::Circle {
+            center: Vec2D::ZERO,
+            radius: 8.0,
+            color: ShapeColor::new(0.22, 0.24, 0.3, 0.95),
+            fill: true,
+            stroke_width: 1.0,
+        });
+        // 指示灯
+        shapes.push(GeometryShape::Circle {
+            center: Vec2D::ZERO,
+            radius: 3.0,
+            color: ShapeColor::new(1.0, 0.3, 0.2, 0.95),
+            fill: true,
+            stroke_width: 1.0,
+        });
+        Self {
+            name: "proximity_mine".to_string(),
+            shapes,
+            collision: CollisionShape::Circle { radius: 10.0 },
+            scale: 1.0,
+        }
+    }
+
+    /// 火焰：外焰 + 内焰的火团（用于 Flamethrower）
+    pub fn flame_puff() -> Self {
+        Self {
+            name: "flame_puff".to_string(),
+            shapes: vec![
+                GeometryShape::Circle {
+                    center: Vec2D::ZERO,
+                    radius: 9.0,
+                    color: ShapeColor::new(1.0, 0.35, 0.1, 0.35),
+                    fill: true,
+                    stroke_width: 1.0,
+                },
+                GeometryShape::Circle {
+                    center: Vec2D::new(0.0, -1.5),
+                    radius: 5.0,
+                    color: ShapeColor::new(1.0, 0.8, 0.3, 0.55),
+                    fill: true,
+                    stroke_width: 1.0,
+                },
+            ],
+            collision: CollisionShape::Circle { radius: 12.0 },
+            scale: 1.0,
+        }
+    }
+
+    /// 引力井：暗色核心 + 同心吸积环，外环半径 80（用于 GravityWell）
+    pub fn gravity_well() -> Self {
+        Self::vortex("gravity_well", ShapeColor::new(0.6, 0.4, 1.0, 1.0))
+    }
+
+    /// 炎狱漩涡：橙红色的引力井（用于 InfernoVortex）
+    pub fn inferno_vortex() -> Self {
+        Self::vortex("inferno_vortex", ShapeColor::new(1.0, 0.5, 0.15, 1.0))
+    }
+
+    /// 漩涡：按主色生成吸积环与外圈光晕
+    fn vortex(name: &str, color: ShapeColor) -> Self {
+        let tint = |alpha: f32| ShapeColor::new(color.r, color.g, color.b, alpha);
+        let ring = |radius: f32, alpha: f32, stroke_width: f32| GeometryShape::Arc {
+            center: Vec2D::ZERO,
+            radius,
+            start_angle: 0.0,
+            end_angle: PI * 1.5,
+            color: tint(alpha),
+            stroke_width,
+        };
+        Self {
+            name: name.to_string(),
+            shapes: vec![
+                GeometryShape::Circle {
+                    center: Vec2D::ZERO,
+                    radius: 80.0,
+                    color: ShapeColor::new(color.r * 0.6, color.g * 0.5, color.b * 0.7, 0.08),
+                    fill: true,
+                    stroke_width: 1.0,
+                },
+                ring(80.0, 0.25, 2.0),
+                ring(55.0, 0.35, 2.5),
+                ring(32.0, 0.5, 3.0),
+                GeometryShape::Circle {
+                    center: Vec2D::ZERO,
+                    radius: 12.0,
+                    color: ShapeColor::new(0.08, 0.02, 0.15, 0.95),
+                    fill: true,
+                    stroke_width: 1.0,
+                },
+                GeometryShape::Circle {
+                    center: Vec2D::ZERO,
+                    radius: 13.0,
+                    color: tint(0.8),
+                    fill: false,
+                    stroke_width: 2.0,
+                },
+            ],
+            collision: CollisionShape::Circle { radius: 80.0 },
+            scale: 1.0,
+        }
+    }
+
     /// 精英敌人：侦察机（大体积、精巧、慢速）
     pub fn elite_scout() -> Self {
         Self {
@@ -1001,7 +1179,22 @@ impl GeometryBlueprint {
                     stroke_width: 3.0,
                 },
             ],
-            collision: CollisionShape::Circle { radius: 26.0 },
+            // 细长机身 + 后掠主翼轮廓（凹多边形）
+            collision: CollisionShape::Polygon {
+                vertices: vec![
+                    Vec2D::new(0.0, 28.0),
+                    Vec2D::new(-10.0, 16.0),
+                    Vec2D::new(-18.0, 8.0),
+                    Vec2D::new(-34.0, -16.0),
+                    Vec2D::new(-13.0, -10.0),
+                    Vec2D::new(-8.0, -28.0),
+                    Vec2D::new(8.0, -28.0),
+                    Vec2D::new(13.0, -10.0),
+                    Vec2D::new(34.0, -16.0),
+                    Vec2D::new(18.0, 8.0),
+                    Vec2D::new(10.0, 16.0),
+                ],
+            },
             scale: 1.0,
         }
     }
@@ -1085,7 +1278,17 @@ impl GeometryBlueprint {
                     stroke_width: 3.0,
                 },
             ],
-            collision: CollisionShape::Circle { radius: 30.0 },
+            collision: CollisionShape::Polygon {
+                vertices: vec![
+                    Vec2D::new(0.0, 26.0),
+                    Vec2D::new(-14.0, 12.0),
+                    Vec2D::new(-18.0, -10.0),
+                    Vec2D::new(-10.0, -30.0),
+                    Vec2D::new(10.0, -30.0),
+                    Vec2D::new(18.0, -10.0),
+                    Vec2D::new(14.0, 12.0),
+                ],
+            },
             scale: 1.0,
         }
     }
@@ -1214,6 +1417,34 @@ impl GeometryBlueprint {
         }
     }
 
+    /// 积分宝石（小菱形）
+    pub fn power_up_score_gem() -> Self {
+        Self {
+            name: "power_up_score_gem".to_string(),
+            shapes: vec![
+                GeometryShape::Polygon {
+                    vertices: vec![
+                        Vec2D::new(0.0, 8.0),
+                        Vec2D::new(-6.0, 0.0),
+                        Vec2D::new(0.0, -8.0),
+                        Vec2D::new(6.0, 0.0),
+                    ],
+                    color: ShapeColor::new(0.35, 0.95, 1.0, 0.95),
+                    fill: true,
+                    stroke_width: 1.0,
+                },
+                GeometryShape::Line {
+                    start: Vec2D::new(-3.0, 0.0),
+                    end: Vec2D::new(3.0, 0.0),
+                    color: ShapeColor::new(1.0, 1.0, 1.0, 0.5),
+                    stroke_width: 1.0,
+                },
+            ],
+            collision: CollisionShape::Circle { radius: 9.0 },
+            scale: 1.0,
+        }
+    }
+
     /// 护盾道具（小盾牌）
     pub fn power_up_shield() -> Self {
         Self {
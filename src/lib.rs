@@ -10,11 +10,15 @@ pub mod ui;
 use bevy::prelude::*;
 use bevy::window::WindowResolution;
 
-use entities::{BossPlugin, BulletPlugin, EnemyPlugin, PlayerPlugin, ShieldPlugin};
+use entities::{
+    BossMechanicsPlugin, BossPartPlugin, BossPlugin, BulletBehaviorPlugin, BulletPatternPlugin,
+    BulletPlugin, DamagePlugin, EnemyPlugin, GameplayEventsPlugin, PassivePlugin, PlayerPlugin,
+    ShieldPlugin, SpecialWeaponPlugin, TelegraphPlugin,
+};
 use game::{CollisionPlugin, GameConfig, GameStatePlugin, ScrollPlugin};
 use geometry::GeometryRendererPlugin;
 use storage::{RechargePlugin, StoragePlugin};
-use ui::{EnhancePlugin, HudPlugin, InputPlugin, MenuPlugin, UpgradePlugin};
+use ui::{BossCinematicsPlugin, EnhancePlugin, HudPlugin, InputPlugin, MenuPlugin, UpgradePlugin};
 
 /// 游戏主插件
 pub struct ShootGamePlugin;
@@ -30,10 +34,19 @@ impl Plugin for ShootGamePlugin {
             .add_plugins(GeometryRendererPlugin)
             // 实体系统
             .add_plugins(PlayerPlugin)
+            .add_plugins(SpecialWeaponPlugin)
+            .add_plugins(PassivePlugin)
             .add_plugins(EnemyPlugin)
             .add_plugins(BulletPlugin)
+            .add_plugins(BulletBehaviorPlugin)
+            .add_plugins(BulletPatternPlugin)
+            .add_plugins(TelegraphPlugin)
             .add_plugins(ShieldPlugin)
             .add_plugins(BossPlugin)
+            .add_plugins(BossPartPlugin)
+            .add_plugins(BossMechanicsPlugin)
+            .add_plugins(DamagePlugin)
+            .add_plugins(GameplayEventsPlugin)
             // 存储和网络
             .add_plugins(StoragePlugin)
             .add_plugins(RechargePlugin)
@@ -41,6 +54,7 @@ impl Plugin for ShootGamePlugin {
             .add_plugins(MenuPlugin)
             .add_plugins(EnhancePlugin)
             .add_plugins(HudPlugin)
+            .add_plugins(BossCinematicsPlugin)
             .add_plugins(InputPlugin)
             .add_plugins(UpgradePlugin)
             // 初始化
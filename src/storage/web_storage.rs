@@ -59,6 +59,13 @@ pub struct GameSettings {
     pub sound_volume: f32,
     /// 音乐音量 (0.0 - 1.0)
     pub music_volume: f32,
+    /// 自动射击（关闭后按住空格/Z 射击，默认子弹可蓄力）
+    #[serde(default = "default_auto_fire")]
+    pub auto_fire: bool,
+}
+
+fn default_auto_fire() -> bool {
+    true
 }
 
 impl Default for GameSettings {
@@ -68,6 +75,7 @@ impl Default for GameSettings {
             music_enabled: true,
             sound_volume: 0.7,
             music_volume: 0.5,
+            auto_fire: true,
         }
     }
 }
@@ -0,0 +1,518 @@
+//! Boss 战演出
+//! 出场 WARNING 与名称卡、击破时的连锁爆炸/闪屏/慢动作、战后结算
+//! （多 Boss 战在最后一个 Boss 被击破后结算）
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::entities::{
+    create_boss_blueprint, spawn_boss_hit_flash, spawn_rocket_explosion_particles, BossDefeated,
+    BossSpawned, BossState, BossType, PlayerDamaged, MID_BOSS_SCALE,
+};
+use crate::game::{not_upgrading, GameData, GameState};
+use crate::geometry::{spawn_geometry_entity, GeometryShape};
+
+/// Boss 演出插件
+pub struct BossCinematicsPlugin;
+
+impl Plugin for BossCinematicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Menu), cleanup_cinematics)
+            .add_systems(OnEnter(GameState::GameOver), cleanup_cinematics)
+            .add_systems(OnEnter(GameState::Recharge), cleanup_cinematics)
+            .add_systems(
+                Update,
+                (
+                    track_boss_fight,
+                    spawn_boss_intro,
+                    update_boss_intro,
+                    update_screen_flash,
+                    update_boss_tally,
+                )
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not_upgrading),
+            )
+            // 击破演出按真实时间推进，不受升级面板暂停：
+            // 击杀 Boss 升级时 BossDefeated 与升级面板同帧出现，消息不能漏读，慢动作也要照常恢复
+            .add_systems(
+                Update,
+                (start_defeat_sequence, update_defeat_sequence)
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// WARNING 名称卡显示时长
+const INTRO_TIME: f32 = 3.0;
+/// 击破演出时长（真实时间）
+const DEFEAT_SEQUENCE_TIME: f32 = 1.8;
+/// 连锁爆炸间隔（真实时间）
+const DEFEAT_BLAST_INTERVAL: f32 = 0.12;
+/// 击破演出期间的时间流速
+const DEFEAT_SLOW_MOTION: f32 = 0.3;
+/// 结算面板显示时长
+const TALLY_TIME: f32 = 4.0;
+/// 闪屏淡出时长
+const FLASH_TIME: f32 = 0.5;
+
+/// WARNING 名称卡
+#[derive(Component)]
+struct BossIntroCard {
+    timer: f32,
+}
+
+/// WARNING 文字（闪烁）
+#[derive(Component)]
+struct BossIntroWarning;
+
+/// 击破演出：Boss 残骸上的连锁爆炸
+#[derive(Component)]
+struct BossWreck {
+    boss_type: BossType,
+    mid_boss: bool,
+    /// 本场战斗最后一个 Boss（演出结束后结算）
+    final_boss: bool,
+    timer: f32,
+    blast_timer: f32,
+    /// 爆炸点（取自 Boss 蓝图的顶点与圆心，相对残骸中心）
+    blast_points: Vec<Vec2>,
+}
+
+/// 全屏闪白
+#[derive(Component)]
+struct ScreenFlash {
+    timer: f32,
+}
+
+/// 战后结算面板
+#[derive(Component)]
+struct BossTally {
+    timer: f32,
+}
+
+/// 统计本场 Boss 战用时与受击次数
+fn track_boss_fight(
+    time: Res<Time>,
+    mut boss_state: ResMut<BossState>,
+    mut player_damaged: MessageReader<PlayerDamaged>,
+) {
+    let hits = player_damaged.read().count() as u32;
+    if !boss_state.active {
+        return;
+    }
+    boss_state.elapsed += time.delta_secs();
+    boss_state.damage_taken += hits;
+}
+
+/// Boss 出场：WARNING + 名称卡（同时出场的 Boss 合并为一张；中型 Boss 显示 CAUTION）
+fn spawn_boss_intro(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut boss_spawned: MessageReader<BossSpawned>,
+) {
+    let events: Vec<&BossSpawned> = boss_spawned.read().collect();
+    if events.is_empty() {
+        return;
+    }
+    let mid_boss = events.iter().all(|e| e.mid_boss);
+    let names = events
+        .iter()
+        .map(|e| e.boss_type.name())
+        .collect::<Vec<_>>()
+        .join(" & ");
+    let (warning, subtitle) = if mid_boss {
+        ("⚠ CAUTION ⚠", "中型敌舰接近中")
+    } else if events.len() > 1 {
+        ("⚠ WARNING ⚠", "多个强敌同时接近中")
+    } else {
+        ("⚠ WARNING ⚠", "强敌接近中")
+    };
+    let font = asset_server.load("NotoSansCJKsc-Regular.otf");
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(30.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::vertical(Val::Px(12.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.4, 0.0, 0.0, 0.45)),
+            GlobalZIndex(50),
+            BossIntroCard { timer: INTRO_TIME },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(warning),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.2, 0.2)),
+                BossIntroWarning,
+            ));
+            parent.spawn((
+                Text::new(format!("◆ {} ◆", names)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            parent.spawn((
+                Text::new(subtitle),
+                TextFont {
+                    font,
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.7, 0.7)),
+            ));
+        });
+}
+
+/// WARNING 闪烁，时间到后移除名称卡
+fn update_boss_intro(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cards: Query<(Entity, &mut BossIntroCard)>,
+    mut warnings: Query<&mut TextColor, With<BossIntroWarning>>,
+) {
+    for (entity, mut card) in cards.iter_mut() {
+        card.timer -= time.delta_secs();
+        if card.timer <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = if (card.timer * 4.0) as i32 % 2 == 0 {
+            1.0
+        } else {
+            0.25
+        };
+        for mut color in warnings.iter_mut() {
+            color.0 = Color::srgba(1.0, 0.2, 0.2, alpha);
+        }
+    }
+}
+
+/// Boss 击破：生成残骸、闪屏并进入慢动作
+fn start_defeat_sequence(
+    mut commands: Commands,
+    mut boss_defeated: MessageReader<BossDefeated>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    for event in boss_defeated.read() {
+        let mut blueprint = create_boss_blueprint(event.boss_type);
+        if event.mid_boss {
+            blueprint.scale *= MID_BOSS_SCALE;
+        }
+        let blast_points = blueprint
+            .shapes
+            .iter()
+            .flat_map(|shape| match shape {
+                GeometryShape::Polygon { vertices, .. } => {
+                    vertices.iter().map(|v| Vec2::new(v.x, v.y) * 0.8).collect()
+                }
+                GeometryShape::Circle { center, radius, .. }
+                | GeometryShape::Arc { center, radius, .. } => vec![
+                    Vec2::new(center.x, center.y),
+                    Vec2::new(center.x + radius * 0.6, center.y),
+                    Vec2::new(center.x - radius * 0.6, center.y),
+                ],
+                GeometryShape::Line { start, end, .. } => {
+                    vec![Vec2::new(start.x, start.y), Vec2::new(end.x, end.y)]
+                }
+            })
+            .collect();
+
+        let wreck = spawn_geometry_entity(&mut commands, &blueprint, event.position);
+        commands.entity(wreck).insert(BossWreck {
+            boss_type: event.boss_type,
+            mid_boss: event.mid_boss,
+            final_boss: event.final_boss,
+            timer: DEFEAT_SEQUENCE_TIME,
+            blast_timer: 0.0,
+            blast_points,
+        });
+        spawn_screen_flash(&mut commands, 0.6);
+        virtual_time.set_relative_speed(DEFEAT_SLOW_MOTION);
+    }
+}
+
+/// 连锁爆炸；结束时大爆炸、闪屏、恢复时间流速并显示结算
+fn update_defeat_sequence(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    asset_server: Res<AssetServer>,
+    mut game_data: ResMut<GameData>,
+    boss_state: Res<BossState>,
+    mut wrecks: Query<(Entity, &Transform, &mut BossWreck)>,
+) {
+    let delta = real_time.delta_secs();
+    let mut rng = rand::rng();
+    // 多 Boss 先后击破时演出会重叠，最后一段演出结束才恢复时间流速
+    let mut active = wrecks.iter().count();
+
+    for (entity, transform, mut wreck) in wrecks.iter_mut() {
+        wreck.timer -= delta;
+        wreck.blast_timer -= delta;
+
+        if wreck.blast_timer <= 0.0 && !wreck.blast_points.is_empty() {
+            wreck.blast_timer = DEFEAT_BLAST_INTERVAL;
+            let point = wreck.blast_points[rng.random_range(0..wreck.blast_points.len())];
+            let position = transform.translation + point.extend(1.0);
+            spawn_rocket_explosion_particles(&mut commands, position, 10, 140.0);
+            spawn_boss_hit_flash(&mut commands, position);
+        }
+
+        if wreck.timer > 0.0 {
+            continue;
+        }
+
+        spawn_rocket_explosion_particles(&mut commands, transform.translation, 28, 280.0);
+        spawn_screen_flash(&mut commands, 0.9);
+        commands.entity(entity).despawn();
+        active -= 1;
+        if active == 0 {
+            virtual_time.set_relative_speed(1.0);
+        }
+
+        if !wreck.final_boss {
+            continue;
+        }
+        let mut bonus = tally_bonus(boss_state.elapsed, boss_state.damage_taken);
+        if wreck.mid_boss {
+            bonus /= 2;
+        }
+        game_data.add_score_only(bonus);
+        let title = if wreck.mid_boss {
+            format!("中型 {} 击破！", wreck.boss_type.name())
+        } else {
+            format!("{} 击破！", wreck.boss_type.name())
+        };
+        spawn_boss_tally(
+            &mut commands,
+            &asset_server,
+            title,
+            boss_state.elapsed,
+            boss_state.damage_taken,
+            bonus,
+        );
+    }
+}
+
+/// 结算奖励：越快越多，无伤额外奖励
+fn tally_bonus(elapsed: f32, damage_taken: u32) -> u32 {
+    let time_bonus = ((90.0 - elapsed).max(0.0) * 50.0) as u32;
+    let damage_bonus = match damage_taken {
+        0 => 5000,
+        n => 3000u32.saturating_sub(n * 1000),
+    };
+    time_bonus + damage_bonus
+}
+
+fn spawn_screen_flash(commands: &mut Commands, alpha: f32) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, alpha)),
+        GlobalZIndex(60),
+        ScreenFlash { timer: FLASH_TIME },
+    ));
+}
+
+/// 闪屏淡出（真实时间，不受慢动作影响）
+fn update_screen_flash(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    mut flashes: Query<(Entity, &mut ScreenFlash, &mut BackgroundColor)>,
+) {
+    for (entity, mut flash, mut color) in flashes.iter_mut() {
+        flash.timer -= real_time.delta_secs();
+        if flash.timer <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = color.0.alpha().min(flash.timer / FLASH_TIME);
+        color.0.set_alpha(alpha);
+    }
+}
+
+/// 战后结算面板
+fn spawn_boss_tally(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    title: String,
+    elapsed: f32,
+    damage_taken: u32,
+    bonus: u32,
+) {
+    let font = asset_server.load("NotoSansCJKsc-Regular.otf");
+    let lines = [
+        (title, 30.0, Color::srgb(1.0, 0.85, 0.3)),
+        (format!("用时 {:.1} 秒", elapsed), 20.0, Color::WHITE),
+        (format!("受到攻击 {} 次", damage_taken), 20.0, Color::WHITE),
+        (
+            format!("奖励分数 +{}", bonus),
+            24.0,
+            Color::srgb(0.4, 1.0, 0.5),
+        ),
+    ];
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(28.0),
+                left: Val::Percent(20.0),
+                width: Val::Percent(60.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.8)),
+            GlobalZIndex(50),
+            BossTally { timer: TALLY_TIME },
+        ))
+        .with_children(|parent| {
+            for (text, size, color) in lines {
+                parent.spawn((
+                    Text::new(text),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: size,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            }
+        });
+}
+
+fn update_boss_tally(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut tallies: Query<(Entity, &mut BossTally)>,
+) {
+    for (entity, mut tally) in tallies.iter_mut() {
+        tally.timer -= time.delta_secs();
+        if tally.timer <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// 离开游戏时清理演出并恢复时间流速
+fn cleanup_cinematics(
+    mut commands: Commands,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    cards: Query<Entity, With<BossIntroCard>>,
+    wrecks: Query<Entity, With<BossWreck>>,
+    flashes: Query<Entity, With<ScreenFlash>>,
+    tallies: Query<Entity, With<BossTally>>,
+) {
+    virtual_time.set_relative_speed(1.0);
+    let entities = cards
+        .iter()
+        .chain(wrecks.iter())
+        .chain(flashes.iter())
+        .chain(tallies.iter());
+    for entity in entities {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::AssetPlugin;
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+
+    fn cinematics_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<BossState>()
+            .insert_resource(GameData::new())
+            .add_message::<BossSpawned>()
+            .add_message::<BossDefeated>()
+            .add_message::<PlayerDamaged>()
+            .add_plugins(BossCinematicsPlugin);
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app
+    }
+
+    #[test]
+    fn defeat_sequence_starts_while_upgrading() {
+        let mut app = cinematics_app();
+        // 击杀 Boss 的同一帧升级，升级面板已打开
+        app.world_mut().resource_mut::<GameData>().upgrading = true;
+        app.world_mut().write_message(BossDefeated {
+            entity: Entity::PLACEHOLDER,
+            boss_type: BossType::DiamondKing,
+            position: Vec3::ZERO,
+            score: 1000,
+            mid_boss: false,
+            final_boss: true,
+        });
+        app.update();
+
+        let world = app.world_mut();
+        let wrecks = world.query::<&BossWreck>().iter(world).count();
+        assert_eq!(wrecks, 1);
+        let speed = world.resource::<Time<Virtual>>().relative_speed();
+        assert_eq!(speed, DEFEAT_SLOW_MOTION);
+    }
+
+    #[test]
+    fn overlapping_defeat_sequences_keep_slow_motion_until_the_last_ends() {
+        let mut app = cinematics_app();
+        for boss_type in [BossType::DiamondKing, BossType::HexFortress] {
+            app.world_mut().write_message(BossDefeated {
+                entity: Entity::PLACEHOLDER,
+                boss_type,
+                position: Vec3::ZERO,
+                score: 1000,
+                mid_boss: false,
+                // 不生成结算面板（测试环境没有字体资源）
+                final_boss: false,
+            });
+        }
+        app.update();
+
+        let finish = |app: &mut App, boss_type: BossType| {
+            let world = app.world_mut();
+            for mut wreck in world.query::<&mut BossWreck>().iter_mut(world) {
+                if wreck.boss_type == boss_type {
+                    wreck.timer = 0.0;
+                }
+            }
+            app.update();
+            let world = app.world_mut();
+            let wrecks = world.query::<&BossWreck>().iter(world).count();
+            (wrecks, world.resource::<Time<Virtual>>().relative_speed())
+        };
+        assert_eq!(
+            finish(&mut app, BossType::DiamondKing),
+            (1, DEFEAT_SLOW_MOTION)
+        );
+        assert_eq!(finish(&mut app, BossType::HexFortress), (0, 1.0));
+    }
+}
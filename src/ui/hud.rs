@@ -1,10 +1,15 @@
 //! HUD (Head-Up Display) 游戏内界面
 
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
-use crate::entities::{BossState, Player, WeaponInventory, WeaponType, MAX_WEAPON_LEVEL};
-use crate::game::{GameData, GameState};
+use crate::entities::{
+    Boss, BossPartKind, BossPhaseChanged, BossState, PassiveItems, PassiveType, Player,
+    WeaponInventory, WeaponType, BOSS_PHASE_THRESHOLDS, MAX_PASSIVE_LEVEL, MAX_WEAPON_LEVEL,
+};
+use crate::game::{GameData, GameState, COMBO_TIMEOUT};
 
 /// 满血/满盾等情况的浮动分数提示
 #[derive(Message, Debug, Clone, Copy)]
@@ -27,7 +32,10 @@ impl Plugin for HudPlugin {
                 Update,
                 (
                     update_hud,
+                    update_combo_hud,
+                    update_passives_hud,
                     update_boss_hud,
+                    update_boss_phase_hud,
                     spawn_floating_score_texts,
                     update_floating_score_texts,
                 )
@@ -75,21 +83,33 @@ struct ExpBarText;
 #[derive(Component)]
 struct WeaponsText;
 
+/// 被动道具列表标记
+#[derive(Component)]
+struct PassivesText;
+
 /// 等级文本标记
 #[derive(Component)]
 struct LevelText;
 
-/// Boss血量条根节点
+/// 连击计量文本标记
+#[derive(Component)]
+struct ComboText;
+
+/// Boss血量条根节点（容纳多条血量条）
 #[derive(Component)]
 struct BossHudRoot;
 
+/// 单个Boss的血量条面板（序号对应 BossState::bosses）
+#[derive(Component)]
+struct BossBarPanel(usize);
+
 /// Boss名称文本
 #[derive(Component)]
-struct BossNameText;
+struct BossNameText(usize);
 
 /// Boss血量百分比文本
 #[derive(Component)]
-struct BossHealthText;
+struct BossHealthText(usize);
 
 /// Boss血量条背景
 #[derive(Component)]
@@ -97,7 +117,24 @@ struct BossHealthBarBg;
 
 /// Boss血量条填充
 #[derive(Component)]
-struct BossHealthBarFill;
+struct BossHealthBarFill(usize);
+
+/// 同时显示的Boss血量条数量
+const MAX_BOSS_BARS: usize = 2;
+
+/// Boss阶段切换横幅
+#[derive(Component)]
+struct BossPhaseBanner;
+
+/// 阶段横幅显示时长（秒）
+const PHASE_BANNER_TIME: f32 = 2.0;
+
+/// 阶段横幅状态：各 Boss 已显示过的阶段与横幅剩余时间
+#[derive(Default)]
+struct PhaseBannerState {
+    shown: HashMap<Entity, u32>,
+    timer: f32,
+}
 
 /// 设置 HUD
 fn setup_hud(
@@ -161,6 +198,16 @@ fn setup_hud(
                                 TextColor(Color::srgb(0.5, 1.0, 0.5)),
                                 LevelText,
                             ));
+                            parent.spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 18.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.6, 0.2)),
+                                ComboText,
+                            ));
                         });
 
                     // 中间：金币和经验条
@@ -224,15 +271,27 @@ fn setup_hud(
                         });
                 });
 
-            // 底部武器栏
+            // 底部武器栏（上方为被动道具栏）
             parent
                 .spawn((Node {
                     position_type: PositionType::Absolute,
                     bottom: Val::Px(10.0),
                     left: Val::Px(10.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
                     ..default()
                 },))
                 .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.75, 0.85, 1.0)),
+                        PassivesText,
+                    ));
                     parent.spawn((
                         Text::new("武器: 无"),
                         TextFont {
@@ -258,7 +317,27 @@ fn setup_hud(
                 FloatingTextLayer,
             ));
 
-            // Boss血量条（初始隐藏）
+            // Boss阶段切换横幅（初始隐藏）
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.85, 0.3)),
+                TextLayout::new_with_justify(Justify::Center),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(35.0),
+                    width: Val::Percent(100.0),
+                    display: Display::None,
+                    ..default()
+                },
+                BossPhaseBanner,
+            ));
+
+            // Boss血量条（初始隐藏，多 Boss 战时上下排列）
             parent
                 .spawn((
                     Node {
@@ -267,69 +346,149 @@ fn setup_hud(
                         left: Val::Percent(10.0),
                         width: Val::Percent(80.0),
                         flex_direction: FlexDirection::Column,
-                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(8.0),
                         display: Display::None, // 初始隐藏
                         ..default()
                     },
                     BossHudRoot,
                 ))
                 .with_children(|parent| {
-                    // Boss名称
-                    parent.spawn((
-                        Text::new(""),
-                        TextFont {
-                            font: font.clone(),
-                            font_size: 20.0,
-                            ..default()
-                        },
-                        TextColor(Color::srgb(1.0, 0.3, 0.3)),
-                        BossNameText,
-                    ));
+                    for slot in 0..MAX_BOSS_BARS {
+                        spawn_boss_bar(parent, &font, slot);
+                    }
+                });
+        });
+}
 
-                    // 血量条容器
-                    parent
-                        .spawn((
-                            Node {
-                                width: Val::Percent(100.0),
-                                height: Val::Px(20.0),
-                                margin: UiRect::top(Val::Px(5.0)),
-                                ..default()
-                            },
-                            BackgroundColor(Color::srgba(0.2, 0.2, 0.2, 0.8)),
-                            BossHealthBarBg,
-                        ))
-                        .with_children(|parent| {
-                            // 血量填充条
-                            parent.spawn((
-                                Node {
-                                    width: Val::Percent(100.0),
-                                    height: Val::Percent(100.0),
-                                    ..default()
-                                },
-                                BackgroundColor(Color::srgb(1.0, 0.2, 0.2)),
-                                BossHealthBarFill,
-                            ));
-                        });
+/// 单个Boss的血量条面板：名称、分段血量条、血量与部件/机制状态
+fn spawn_boss_bar(parent: &mut ChildSpawnerCommands, font: &Handle<Font>, slot: usize) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BossBarPanel(slot),
+        ))
+        .with_children(|parent| {
+            // Boss名称
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.3, 0.3)),
+                BossNameText(slot),
+            ));
 
-                    // 血量百分比文字
+            // 血量条容器
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(20.0),
+                        margin: UiRect::top(Val::Px(5.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.2, 0.2, 0.2, 0.8)),
+                    BossHealthBarBg,
+                ))
+                .with_children(|parent| {
+                    // 血量填充条
                     parent.spawn((
-                        Text::new("100%"),
-                        TextFont {
-                            font: font.clone(),
-                            font_size: 16.0,
-                            ..default()
-                        },
-                        TextColor(Color::WHITE),
                         Node {
-                            margin: UiRect::top(Val::Px(3.0)),
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
                             ..default()
                         },
-                        BossHealthText,
+                        BackgroundColor(Color::srgb(1.0, 0.2, 0.2)),
+                        BossHealthBarFill(slot),
                     ));
+                    // 阶段分段标记
+                    for threshold in BOSS_PHASE_THRESHOLDS {
+                        parent.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Percent(threshold * 100.0),
+                                width: Val::Px(2.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
+                        ));
+                    }
                 });
+
+            // 血量百分比文字
+            parent.spawn((
+                Text::new("100%"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::top(Val::Px(3.0)),
+                    ..default()
+                },
+                BossHealthText(slot),
+            ));
         });
 }
 
+/// Boss阶段：切换时显示横幅（按 Boss 实体分别记录已显示的阶段），
+/// 各血量条颜色随对应 Boss 的阶段变化
+fn update_boss_phase_hud(
+    time: Res<Time>,
+    boss_state: Res<BossState>,
+    mut phase_changed: MessageReader<BossPhaseChanged>,
+    bosses: Query<(), With<Boss>>,
+    mut banner: Local<PhaseBannerState>,
+    mut banner_query: Query<(&mut Text, &mut Node), With<BossPhaseBanner>>,
+    mut fill_query: Query<(&mut BackgroundColor, &BossHealthBarFill)>,
+) {
+    banner.shown.retain(|&entity, _| bosses.contains(entity));
+    for event in phase_changed.read() {
+        let shown = banner.shown.entry(event.entity).or_insert(1);
+        if event.phase <= *shown {
+            continue;
+        }
+        *shown = event.phase;
+        banner.timer = PHASE_BANNER_TIME;
+        if let Ok((mut text, _)) = banner_query.single_mut() {
+            let title = if event.phase >= 3 {
+                "最终阶段"
+            } else {
+                "阶段突破"
+            };
+            **text = format!("PHASE {}\n{}", event.phase, title);
+        }
+    }
+
+    banner.timer = (banner.timer - time.delta_secs()).max(0.0);
+    if let Ok((_, mut node)) = banner_query.single_mut() {
+        node.display = if banner.timer > 0.0 {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+
+    for (mut color, fill) in fill_query.iter_mut() {
+        let boss_phase = boss_state.bosses.get(fill.0).map_or(1, |b| b.phase);
+        color.0 = match boss_phase {
+            0 | 1 => Color::srgb(1.0, 0.2, 0.2),
+            2 => Color::srgb(1.0, 0.55, 0.1),
+            _ => Color::srgb(0.85, 0.2, 1.0),
+        };
+    }
+}
+
 fn spawn_floating_score_texts(
     mut commands: Commands,
     mut events: MessageReader<FloatingScoreEvent>,
@@ -591,6 +750,13 @@ fn update_hud(
                             WeaponType::Lightning => "电",
                             WeaponType::Aura => "球",
                             WeaponType::Beam => "波",
+                            WeaponType::Boomerang => "回",
+                            WeaponType::Mine => "雷",
+                            WeaponType::Flamethrower => "火",
+                            WeaponType::GravityWell => "引",
+                            WeaponType::ArcLaser => "弧",
+                            WeaponType::SwarmMissiles => "蜂",
+                            WeaponType::InfernoVortex => "炎",
                         };
                         format!("{}Lv{}/{}", name, w.level, MAX_WEAPON_LEVEL)
                     })
@@ -601,13 +767,71 @@ fn update_hud(
     }
 }
 
-/// 更新Boss血量HUD
+/// 更新连击计量：连击数、倍率与剩余时间条
+fn update_combo_hud(game_data: Res<GameData>, mut query: Query<&mut Text, With<ComboText>>) {
+    let Ok(mut text) = query.single_mut() else {
+        return;
+    };
+
+    if game_data.combo < 2 {
+        if !text.is_empty() {
+            **text = String::new();
+        }
+        return;
+    }
+
+    let remaining = (game_data.combo_timer / COMBO_TIMEOUT * 8.0)
+        .ceil()
+        .clamp(0.0, 8.0) as usize;
+    **text = format!(
+        "连击 {} x{:.2} {}{}",
+        game_data.combo,
+        game_data.combo_multiplier(),
+        "█".repeat(remaining),
+        "░".repeat(8 - remaining)
+    );
+}
+
+/// 更新被动道具列表（没有道具时不显示）
+fn update_passives_hud(
+    player_query: Query<&PassiveItems, (With<Player>, Changed<PassiveItems>)>,
+    mut query: Query<&mut Text, With<PassivesText>>,
+) {
+    let (Ok(passives), Ok(mut text)) = (player_query.single(), query.single_mut()) else {
+        return;
+    };
+
+    let items: Vec<String> = passives
+        .items
+        .iter()
+        .map(|item| {
+            let name = match item.passive_type {
+                PassiveType::Damage => "攻",
+                PassiveType::FireRate => "射",
+                PassiveType::MoveSpeed => "移",
+                PassiveType::PickupRadius => "磁",
+                PassiveType::ProjectileSpeed => "弹",
+                PassiveType::Experience => "经",
+                PassiveType::Luck => "运",
+            };
+            format!("{}Lv{}/{}", name, item.level, MAX_PASSIVE_LEVEL)
+        })
+        .collect();
+    **text = if items.is_empty() {
+        String::new()
+    } else {
+        format!("被动: {}", items.join(" "))
+    };
+}
+
+/// 更新Boss血量HUD（每个 Boss 一条血量条）
 fn update_boss_hud(
     boss_state: Res<BossState>,
-    mut boss_hud_query: Query<&mut Node, With<BossHudRoot>>,
-    mut boss_name_query: Query<&mut Text, (With<BossNameText>, Without<BossHealthText>)>,
-    mut boss_health_query: Query<&mut Text, (With<BossHealthText>, Without<BossNameText>)>,
-    mut boss_bar_query: Query<&mut Node, (With<BossHealthBarFill>, Without<BossHudRoot>)>,
+    mut boss_hud_query: Query<&mut Node, (With<BossHudRoot>, Without<BossHealthBarFill>)>,
+    mut panel_query: Query<(&mut Node, &BossBarPanel), Without<BossHudRoot>>,
+    mut boss_name_query: Query<(&mut Text, &BossNameText), Without<BossHealthText>>,
+    mut boss_health_query: Query<(&mut Text, &BossHealthText), Without<BossNameText>>,
+    mut boss_bar_query: Query<(&mut Node, &BossHealthBarFill), Without<BossBarPanel>>,
 ) {
     // 显示/隐藏Boss HUD
     if let Ok(mut node) = boss_hud_query.single_mut() {
@@ -622,24 +846,64 @@ fn update_boss_hud(
         return;
     }
 
+    for (mut node, panel) in panel_query.iter_mut() {
+        node.display = if panel.0 < boss_state.bosses.len() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+
     // 更新Boss名称
-    if let Ok(mut text) = boss_name_query.single_mut() {
-        **text = format!("◆ {} ◆", boss_state.boss_name);
+    for (mut text, slot) in boss_name_query.iter_mut() {
+        if let Some(boss) = boss_state.bosses.get(slot.0) {
+            **text = format!("◆ {} ◆", boss.name());
+        }
     }
 
     // 更新血量百分比
-    let percent = boss_state.health_percent();
-    if let Ok(mut text) = boss_health_query.single_mut() {
+    for (mut text, slot) in boss_health_query.iter_mut() {
+        let Some(boss) = boss_state.bosses.get(slot.0) else {
+            continue;
+        };
         **text = format!(
             "{}/{} ({:.1}%)",
-            boss_state.current_health.max(0),
-            boss_state.total_health.max(1),
-            percent
+            boss.current_health.max(0),
+            boss.total_health.max(1),
+            boss.health_percent()
         );
+        // 部件状态：●存活 ○已击毁
+        if !boss.parts.is_empty() {
+            let marks: Vec<String> = [BossPartKind::Turret, BossPartKind::ArmorPlate]
+                .into_iter()
+                .filter_map(|kind| {
+                    let (alive, total) = boss.part_count(kind);
+                    (total > 0).then(|| {
+                        format!(
+                            "{} {}{}",
+                            kind.name(),
+                            "●".repeat(alive),
+                            "○".repeat(total - alive)
+                        )
+                    })
+                })
+                .collect();
+            let core = if boss.core_exposed() {
+                "核心暴露"
+            } else {
+                "核心受装甲保护"
+            };
+            text.push_str(&format!("\n{}  {}", marks.join("  "), core));
+        }
+        if !boss.mechanic_status.is_empty() {
+            text.push_str(&format!("\n{}", boss.mechanic_status));
+        }
     }
 
     // 更新血量条宽度
-    if let Ok(mut node) = boss_bar_query.single_mut() {
-        node.width = Val::Percent(percent);
+    for (mut node, fill) in boss_bar_query.iter_mut() {
+        if let Some(boss) = boss_state.bosses.get(fill.0) {
+            node.width = Val::Percent(boss.health_percent());
+        }
     }
 }
@@ -70,9 +70,19 @@ enum GameOverButton {
 #[derive(Component, Clone, Copy)]
 enum PauseButton {
     Resume,
+    FireMode,
     Menu,
 }
 
+/// 射击模式按钮文字
+fn fire_mode_label(auto_fire: bool) -> &'static str {
+    if auto_fire {
+        "射击: 自动"
+    } else {
+        "射击: 手动"
+    }
+}
+
 /// 菜单金币文本标记
 #[derive(Component)]
 struct MenuCoinsText;
@@ -433,6 +443,24 @@ fn setup_game_over(
                     ..default()
                 },
                 TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+
+            // 本局统计
+            parent.spawn((
+                Text::new(format!(
+                    "擦弹: {}  最大连击: {}",
+                    game_data.run_stats.grazes, game_data.run_stats.max_combo
+                )),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.9, 1.0)),
                 Node {
                     margin: UiRect::bottom(Val::Px(40.0)),
                     ..default()
@@ -483,7 +511,11 @@ fn game_over_button_system(
 }
 
 /// 设置暂停菜单
-fn setup_pause_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn setup_pause_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    save_data: Res<SaveData>,
+) {
     let font = asset_server.load("NotoSansCJKsc-Regular.otf");
 
     commands
@@ -516,6 +548,12 @@ fn setup_pause_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
             ));
 
             spawn_button(parent, &font, "继续游戏", PauseButton::Resume);
+            spawn_button(
+                parent,
+                &font,
+                fire_mode_label(save_data.settings.auto_fire),
+                PauseButton::FireMode,
+            );
             spawn_button(parent, &font, "返回菜单", PauseButton::Menu);
         });
 }
@@ -528,12 +566,14 @@ fn cleanup_pause_menu(mut commands: Commands, query: Query<Entity, With<PauseRoo
 
 fn pause_button_system(
     mut interaction_query: Query<
-        (&Interaction, &PauseButton, &mut BackgroundColor),
+        (&Interaction, &PauseButton, &mut BackgroundColor, &Children),
         Changed<Interaction>,
     >,
+    mut text_query: Query<&mut Text>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut save_data: ResMut<SaveData>,
 ) {
-    for (interaction, button, mut bg_color) in interaction_query.iter_mut() {
+    for (interaction, button, mut bg_color, children) in interaction_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
                 *bg_color = BackgroundColor(Color::srgb(0.0, 0.6, 0.8));
@@ -541,6 +581,18 @@ fn pause_button_system(
                     PauseButton::Resume => {
                         next_state.set(GameState::Playing);
                     }
+                    PauseButton::FireMode => {
+                        // 恢复游戏时由 sync_fire_mode 读取新设置
+                        save_data.settings.auto_fire = !save_data.settings.auto_fire;
+                        for child in children.iter() {
+                            if let Ok(mut text) = text_query.get_mut(child) {
+                                **text = fire_mode_label(save_data.settings.auto_fire).to_string();
+                            }
+                        }
+                        if let Err(e) = crate::storage::save_game(&save_data) {
+                            log::error!("Failed to save settings: {}", e);
+                        }
+                    }
                     PauseButton::Menu => {
                         next_state.set(GameState::Menu);
                     }
@@ -1,11 +1,13 @@
 //! UI 模块
 
+mod boss_cinematics;
 mod hud;
 mod enhance;
 mod input;
 mod menu;
 mod upgrade;
 
+pub use boss_cinematics::*;
 pub use hud::*;
 pub use enhance::*;
 pub use input::*;
@@ -3,7 +3,10 @@
 use bevy::prelude::*;
 use rand::seq::SliceRandom;
 
-use crate::entities::{Player, WeaponInventory, WeaponType, MAX_WEAPON_LEVEL};
+use crate::entities::{
+    Dash, LevelUp, PassiveItems, PassiveType, Player, WeaponAcquired, WeaponInventory, WeaponType,
+    MAX_PASSIVE_LEVEL, MAX_WEAPON_LEVEL,
+};
 use crate::game::{GameData, GameState};
 
 /// 升级界面插件
@@ -36,8 +39,19 @@ enum UpgradeChoice {
         weapon_type: WeaponType,
         is_new: bool,
     },
+    /// 被动道具
+    Passive {
+        passive_type: PassiveType,
+        is_new: bool,
+    },
+    /// 两把满级武器进化为一把进化武器
+    Evolve {
+        weapon_type: WeaponType,
+    },
     RestoreLives,
     RestoreShield,
+    /// 冲刺冷却缩短
+    DashCooldown,
 }
 
 /// 检查是否升级
@@ -45,11 +59,15 @@ fn check_level_up(
     mut commands: Commands,
     mut game_data: ResMut<GameData>,
     asset_server: Res<AssetServer>,
-    player_query: Query<&WeaponInventory, With<Player>>,
+    player_query: Query<(&WeaponInventory, &Dash, &PassiveItems), With<Player>>,
     existing_ui: Query<Entity, With<UpgradeRoot>>,
+    mut level_up: MessageWriter<LevelUp>,
 ) {
     // 经验与等级提升在 `GameData::add_experience` 内完成；
-    // 这里仅在需要升级选择时展示卡牌 UI。
+    // 这里发出每一级的 LevelUp，并在需要升级选择时展示卡牌 UI。
+    for level in game_data.take_level_ups() {
+        level_up.write(LevelUp { level });
+    }
     if !game_data.upgrading {
         return;
     }
@@ -60,13 +78,13 @@ fn check_level_up(
     }
 
     // 获取玩家武器库
-    let Ok(inventory) = player_query.single() else {
+    let Ok((inventory, dash, passives)) = player_query.single() else {
         game_data.upgrading = false;
         return;
     };
 
     // 获取可选择的武器
-    let mut options = get_upgrade_options(&game_data, inventory);
+    let mut options = get_upgrade_options(&game_data, inventory, dash, passives);
 
     if options.is_empty() {
         // 没有可升级的武器，直接完成升级（后续可扩展为“回血/回盾”等）
@@ -77,17 +95,55 @@ fn check_level_up(
     // 随机选择最多3个选项
     let mut rng = rand::rng();
     options.shuffle(&mut rng);
+    // 进化卡优先展示
+    options.sort_by_key(|o| !matches!(o, UpgradeChoice::Evolve { .. }));
     options.truncate(3);
 
     // 创建升级选择界面
-    spawn_upgrade_ui(&mut commands, &asset_server, inventory, &options);
+    spawn_upgrade_ui(
+        &mut commands,
+        &asset_server,
+        inventory,
+        dash,
+        passives,
+        &options,
+    );
 }
 
 /// 获取可升级的武器选项
-fn get_upgrade_options(game_data: &GameData, inventory: &WeaponInventory) -> Vec<UpgradeChoice> {
+fn get_upgrade_options(
+    game_data: &GameData,
+    inventory: &WeaponInventory,
+    dash: &Dash,
+    passives: &PassiveItems,
+) -> Vec<UpgradeChoice> {
     let mut options = Vec::new();
 
-    // 所有武器都满级后，只能选择回血/回盾
+    // 冲刺未满级时始终可选
+    if !dash.is_max_level() {
+        options.push(UpgradeChoice::DashCooldown);
+    }
+
+    // 被动道具：已有但未满级的，以及槽位未满时的新道具
+    for passive_type in PassiveType::all() {
+        let level = passives.level(*passive_type);
+        if (level == 0 && passives.is_full()) || level >= MAX_PASSIVE_LEVEL {
+            continue;
+        }
+        options.push(UpgradeChoice::Passive {
+            passive_type: *passive_type,
+            is_new: level == 0,
+        });
+    }
+
+    // 满足配方的进化
+    for recipe in inventory.available_evolutions() {
+        options.push(UpgradeChoice::Evolve {
+            weapon_type: recipe.result,
+        });
+    }
+
+    // 所有武器都满级后，只能选择回血/回盾（以及冲刺）
     if inventory.all_weapons_maxed() {
         if game_data.lives < game_data.max_lives {
             options.push(UpgradeChoice::RestoreLives);
@@ -96,7 +152,12 @@ fn get_upgrade_options(game_data: &GameData, inventory: &WeaponInventory) -> Vec
             options.push(UpgradeChoice::RestoreShield);
         }
         // 如果都满了，就允许依然给两个选项（无效但可选）
-        if options.is_empty() {
+        if !options.iter().any(|o| {
+            matches!(
+                o,
+                UpgradeChoice::RestoreLives | UpgradeChoice::RestoreShield
+            )
+        }) {
             options.push(UpgradeChoice::RestoreLives);
             options.push(UpgradeChoice::RestoreShield);
         }
@@ -116,7 +177,7 @@ fn get_upgrade_options(game_data: &GameData, inventory: &WeaponInventory) -> Vec
     // 还没有的新武器（如果武器槽未满）
     if inventory.weapons.len() < 5 {
         for wt in WeaponType::all() {
-            if inventory.get_weapon(*wt).is_none() {
+            if inventory.get_weapon(*wt).is_none() && !inventory.is_absorbed(*wt) {
                 options.push(UpgradeChoice::Weapon {
                     weapon_type: *wt,
                     is_new: true,
@@ -144,7 +205,7 @@ fn get_upgrade_info(choice: UpgradeChoice) -> (&'static str, &'static str, Color
             ),
             WeaponType::Laser => (
                 "激光",
-                "细长穿透激光\n升级增加密度",
+                "持续光束灼烧敌人\n升级加宽，5 级起贯穿",
                 Color::srgb(0.3, 1.0, 0.5),
             ),
             WeaponType::Homing => (
@@ -167,7 +228,60 @@ fn get_upgrade_info(choice: UpgradeChoice) -> (&'static str, &'static str, Color
                 "月牙形穿透能量波\n升级增加伤害",
                 Color::srgb(0.8, 0.4, 1.0),
             ),
+            WeaponType::Boomerang => (
+                "回旋刃",
+                "飞出后折返\n往返均可命中",
+                Color::srgb(0.9, 0.95, 0.6),
+            ),
+            WeaponType::Mine => (
+                "感应雷",
+                "在身后布雷\n敌人靠近时爆炸",
+                Color::srgb(1.0, 0.55, 0.2),
+            ),
+            WeaponType::Flamethrower => (
+                "火焰喷射器",
+                "短程锥形火焰\n使敌人持续燃烧",
+                Color::srgb(1.0, 0.45, 0.15),
+            ),
+            WeaponType::GravityWell => (
+                "引力井",
+                "吸引并碾压敌人\n升级扩大范围",
+                Color::srgb(0.6, 0.4, 1.0),
+            ),
+            WeaponType::ArcLaser => (
+                "电弧激光",
+                "激光 + 闪电链\n贯穿光束迸发闪电",
+                Color::srgb(0.5, 0.85, 1.0),
+            ),
+            WeaponType::SwarmMissiles => (
+                "蜂群导弹",
+                "导弹 + 自导导弹\n追踪导弹群命中爆炸",
+                Color::srgb(1.0, 0.5, 0.6),
+            ),
+            WeaponType::InfernoVortex => (
+                "炎狱漩涡",
+                "火焰喷射器 + 引力井\n吸入敌人并点燃",
+                Color::srgb(1.0, 0.35, 0.1),
+            ),
         },
+        UpgradeChoice::Passive { passive_type, .. } => {
+            let (desc, color) = match passive_type {
+                PassiveType::Damage => ("所有武器伤害 +10%", Color::srgb(1.0, 0.45, 0.35)),
+                PassiveType::FireRate => ("武器射速 +8%", Color::srgb(1.0, 0.8, 0.35)),
+                PassiveType::MoveSpeed => ("移动速度 +8%", Color::srgb(0.4, 1.0, 0.7)),
+                PassiveType::PickupRadius => {
+                    ("自动吸附附近道具\n范围 +40", Color::srgb(0.6, 0.7, 1.0))
+                }
+                PassiveType::ProjectileSpeed => ("子弹飞行速度 +10%", Color::srgb(0.5, 0.9, 1.0)),
+                PassiveType::Experience => ("经验获取 +10%", Color::srgb(0.8, 1.0, 0.4)),
+                PassiveType::Luck => ("道具掉落概率 +25%", Color::srgb(1.0, 0.9, 0.5)),
+            };
+            (passive_type.name(), desc, color)
+        }
+        UpgradeChoice::Evolve { weapon_type } => get_upgrade_info(UpgradeChoice::Weapon {
+            weapon_type,
+            is_new: true,
+        }),
         UpgradeChoice::RestoreLives => (
             "恢复生命",
             "立即恢复 1 点生命值",
@@ -178,6 +292,11 @@ fn get_upgrade_info(choice: UpgradeChoice) -> (&'static str, &'static str, Color
             "立即恢复 2 点护盾值",
             Color::srgb(0.3, 0.8, 1.0),
         ),
+        UpgradeChoice::DashCooldown => (
+            "冲刺",
+            "Shift/X 冲刺无敌\n升级缩短冷却",
+            Color::srgb(0.4, 1.0, 0.9),
+        ),
     }
 }
 
@@ -186,6 +305,8 @@ fn spawn_upgrade_ui(
     commands: &mut Commands,
     asset_server: &AssetServer,
     inventory: &WeaponInventory,
+    dash: &Dash,
+    passives: &PassiveItems,
     options: &[UpgradeChoice],
 ) {
     let font = asset_server.load("NotoSansCJKsc-Regular.otf");
@@ -212,8 +333,21 @@ fn spawn_upgrade_ui(
                         format!("Lv{} → Lv{}", current_level, next_level)
                     }
                 }
+                UpgradeChoice::Passive {
+                    passive_type,
+                    is_new,
+                } => {
+                    if *is_new {
+                        "新道具!".to_string()
+                    } else {
+                        let level = passives.level(*passive_type);
+                        format!("Lv{} → Lv{}", level, level + 1)
+                    }
+                }
+                UpgradeChoice::Evolve { .. } => "进化!".to_string(),
                 UpgradeChoice::RestoreLives => "恢复".to_string(),
                 UpgradeChoice::RestoreShield => "恢复".to_string(),
+                UpgradeChoice::DashCooldown => format!("Lv{} → Lv{}", dash.level, dash.level + 1),
             };
             (*choice, name, desc, color, level_text)
         })
@@ -316,9 +450,11 @@ fn spawn_upgrade_ui(
                                         ..default()
                                     },
                                     TextColor(match choice {
-                                        UpgradeChoice::Weapon { is_new: true, .. } => {
+                                        UpgradeChoice::Weapon { is_new: true, .. }
+                                        | UpgradeChoice::Passive { is_new: true, .. } => {
                                             Color::srgb(0.3, 1.0, 0.3)
                                         }
+                                        UpgradeChoice::Evolve { .. } => Color::srgb(1.0, 0.85, 0.2),
                                         _ => Color::srgb(0.7, 0.7, 1.0),
                                     }),
                                     Node {
@@ -347,16 +483,49 @@ fn spawn_upgrade_ui(
 fn handle_upgrade_selection(
     mut commands: Commands,
     mut game_data: ResMut<GameData>,
-    mut player_query: Query<&mut WeaponInventory, With<Player>>,
+    mut player_query: Query<(&mut WeaponInventory, &mut Dash, &mut PassiveItems), With<Player>>,
     interaction_query: Query<(&Interaction, &UpgradeButton), Changed<Interaction>>,
     upgrade_ui: Query<Entity, With<UpgradeRoot>>,
+    mut weapon_acquired: MessageWriter<WeaponAcquired>,
 ) {
     for (interaction, button) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
             match button.choice {
-                UpgradeChoice::Weapon { weapon_type, .. } => {
-                    if let Ok(mut inventory) = player_query.single_mut() {
+                UpgradeChoice::Weapon {
+                    weapon_type,
+                    is_new,
+                } => {
+                    if let Ok((mut inventory, ..)) = player_query.single_mut() {
                         inventory.add_or_upgrade(weapon_type);
+                        if let Some(weapon) = inventory.get_weapon(weapon_type) {
+                            weapon_acquired.write(WeaponAcquired {
+                                weapon_type,
+                                level: weapon.level,
+                                is_new,
+                            });
+                        }
+                    }
+                }
+                UpgradeChoice::Evolve { weapon_type } => {
+                    if let (Ok((mut inventory, ..)), Some(recipe)) =
+                        (player_query.single_mut(), weapon_type.evolution_recipe())
+                    {
+                        inventory.evolve(recipe);
+                        weapon_acquired.write(WeaponAcquired {
+                            weapon_type,
+                            level: MAX_WEAPON_LEVEL,
+                            is_new: true,
+                        });
+                    }
+                }
+                UpgradeChoice::Passive { passive_type, .. } => {
+                    if let Ok((_, _, mut passives)) = player_query.single_mut() {
+                        passives.add_or_upgrade(passive_type);
+                    }
+                }
+                UpgradeChoice::DashCooldown => {
+                    if let Ok((_, mut dash, _)) = player_query.single_mut() {
+                        dash.level_up();
                     }
                 }
                 UpgradeChoice::RestoreLives => {